@@ -27,6 +27,8 @@ pub enum Statement {
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Declaration {
+    /// The KDoc comment preceding the declaration.
+    pub doc: Option<Box<KDoc>>,
     pub annotations: Vec<AnnotationSet>,
     pub kind: DeclarationKind,
 }
@@ -46,11 +48,13 @@ pub enum DeclarationKind {
 pub struct EntityDeclaration {
     pub modifiers: Vec<Modifier>,
     pub kind: EntityDeclarationKind,
-    pub name: String,
+    /// The name of the entity, which only a companion object may omit.
+    pub name: Option<String>,
     pub type_params: Vec<TypeParam>,
     pub primary_constructor: Option<PrimaryConstructorDeclaration>,
     pub constructors: Vec<ConstructorDeclaration>,
-    pub bounds: Vec<TypeBound>,
+    pub extends: Vec<SuperType>,
+    pub bounds: Vec<TypeConstraint>,
     pub inner: Vec<Declaration>,
 }
 
@@ -58,20 +62,33 @@ pub struct EntityDeclaration {
 pub enum EntityDeclarationKind {
     Class,
     Interface,
+    FunInterface,
     Object,
+    DataObject,
     CompanionObject,
     Enum,
     ObjectInstance,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct SuperType {
+    pub ty: Type,
+    pub args: Option<Vec<CallArg>>,
+    pub delegate: Option<Expression>,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct PrimaryConstructorDeclaration {
+    pub annotations: Vec<AnnotationSet>,
     pub modifiers: Vec<Modifier>,
     pub params: Vec<Param>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct ConstructorDeclaration {
+    /// The KDoc comment preceding the constructor.
+    pub doc: Option<Box<KDoc>>,
+    pub annotations: Vec<AnnotationSet>,
     pub modifiers: Vec<Modifier>,
     pub params: Vec<Param>,
     pub delegate: Option<ConstructorDelegate>,
@@ -98,7 +115,7 @@ pub struct FunctionDeclaration {
     pub name: Option<String>,
     pub params: Vec<Param>,
     pub return_ty: Option<Type>,
-    pub bounds: Vec<TypeBound>,
+    pub bounds: Vec<TypeConstraint>,
     pub body: Option<Block>,
 }
 
@@ -110,13 +127,12 @@ pub struct Block {
 #[derive(Debug, PartialEq, Clone)]
 pub struct PropertyDeclaration {
     pub modifiers: Vec<Modifier>,
-    pub is_const: bool,
     pub is_mutable: bool,
     pub is_delegated: bool,
     pub type_params: Vec<TypeParam>,
     pub vars: Tuple,
     pub receiver: Option<Type>,
-    pub bounds: Vec<TypeBound>,
+    pub bounds: Vec<TypeConstraint>,
     pub init: Option<Expression>,
     pub accessors: Vec<PropertyAccessor>,
 }
@@ -132,7 +148,8 @@ pub enum PropertyAccessor {
     Setter {
         annotations: Vec<AnnotationSet>,
         modifiers: Vec<Modifier>,
-        field: PropertySetterField,
+        field: Option<PropertySetterField>,
+        return_ty: Option<Type>,
         body: Option<Block>,
     },
 }
@@ -141,8 +158,6 @@ pub enum PropertyAccessor {
 pub struct PropertySetterField {
     pub name: String,
     pub ty: Option<Type>,
-    pub return_ty: Option<Type>,
-    pub body: Option<Block>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -164,12 +179,17 @@ pub struct EnumEntryDeclaration {
 #[derive(Debug, PartialEq, Clone)]
 pub enum Expression {
     Literal(Literal),
+    Annotated(AnnotatedExpression),
     ArrayAccess(ArrayAccessExpression),
     BinaryOp(BinaryOperation),
+    Block(Block),
     Break(BreakExpression),
     Call(CallExpression),
+    ClassLiteral(ClassLiteralExpression),
+    CollectionLiteral(Vec<Expression>),
     Continue(ContinueExpression),
     For(ForExpression),
+    Function(FunctionDeclaration),
     If(IfExpression),
     Lambda(LambdaBlock),
     Labeled(LabeledExpression),
@@ -178,11 +198,12 @@ pub enum Expression {
     PropertyReference(PropertyReferenceExpression),
     Reference(ReferenceExpression),
     Return(ReturnExpression),
-    StringTemplate(StringTemplateExpression),
+    StringTemplate(Vec<StringTemplateExpression>),
     Super(SuperExpression),
     This(ThisExpression),
     Throw(ThrowExpression),
     Try(TryExpression),
+    Type(Type),
     UnaryOp(UnaryOperation),
     When(WhenExpression),
     While(WhileExpression),
@@ -190,15 +211,39 @@ pub enum Expression {
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Literal {
-    UnsignedInteger(u64),
-    Integer(i64),
-    Decimal(f64),
+    UnsignedInteger(NumberLiteral<u64>),
+    Integer(NumberLiteral<i64>),
+    Decimal(NumberLiteral<f64>),
     String(String),
     Char(char),
     Boolean(bool),
     Null,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct NumberLiteral<T> {
+    pub value: T,
+    /// The literal as written in the source, including separators and suffix.
+    pub text: String,
+    pub radix: Radix,
+    pub suffix: Option<NumberSuffix>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Radix {
+    Binary,
+    Decimal,
+    Hexadecimal,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum NumberSuffix {
+    Long,
+    Unsigned,
+    UnsignedLong,
+    Float,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct IfExpression {
     pub expr: Box<Expression>,
@@ -312,20 +357,32 @@ pub struct SuperExpression {
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct WhenExpression {
+    pub var: Option<VarDefinition>,
     pub expr: Option<Box<Expression>>,
     pub entries: Vec<WhenEntry>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct WhenEntry {
-    pub exprs: Vec<Expression>,
+    /// The conditions of the entry, which are empty for the `else` entry.
+    pub conditions: Vec<WhenCondition>,
+    pub guard: Option<Expression>,
     pub body: Box<Expression>,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub enum WhenCondition {
+    Expression(Expression),
+    In(Expression),
+    NotIn(Expression),
+    Is(Type),
+    IsNot(Type),
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct ObjectExpression {
     pub annotations: Vec<AnnotationSet>,
-    pub extends: Vec<EntityDeclaration>,
+    pub extends: Vec<SuperType>,
     pub inner: Vec<Declaration>,
 }
 
@@ -360,6 +417,12 @@ pub struct ReferenceExpression {
     pub parts: Vec<String>,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct AnnotatedExpression {
+    pub annotations: Vec<AnnotationSet>,
+    pub expr: Box<Expression>,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct LabeledExpression {
     pub label: String,
@@ -377,10 +440,20 @@ pub struct CallExpression {
 #[derive(Debug, PartialEq, Clone)]
 pub struct LambdaBlock {
     pub label: Option<String>,
-    pub vars: Tuple,
+    pub params: Vec<LambdaParam>,
     pub body: Option<Block>,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub enum LambdaParam {
+    Var(VarDefinition),
+    /// A destructured parameter such as `(a, b): Pair<A, B>`.
+    Destructured {
+        vars: Vec<VarDefinition>,
+        ty: Option<Type>,
+    },
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct ArrayAccessExpression {
     pub expr: Box<Expression>,
@@ -393,8 +466,14 @@ pub struct PropertyReferenceExpression {
     pub rhs: Box<Expression>,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct ClassLiteralExpression {
+    pub lhs: Box<Expression>,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum StringTemplateExpression {
+    Literal(String),
     Simple(String),
     Block(Block),
 }
@@ -403,12 +482,15 @@ pub enum StringTemplateExpression {
 pub enum Type {
     Simple(Box<SimpleType>),
     Function(Box<FunctionType>),
+    DefinitelyNonNull(Box<DefinitelyNonNullType>),
+    Annotated(Box<AnnotatedType>),
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct SimpleType {
+    pub qualifier: Option<Box<SimpleType>>,
     pub name: Option<String>,
-    pub type_args: Vec<Type>,
+    pub type_args: Vec<TypeBound>,
     pub is_nullable: bool,
 }
 
@@ -418,6 +500,19 @@ pub struct FunctionType {
     pub params: Vec<AnonymousParam>,
     pub return_ty: Type,
     pub is_nullable: bool,
+    pub is_suspend: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DefinitelyNonNullType {
+    pub lhs: Type,
+    pub rhs: Type,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AnnotatedType {
+    pub annotations: Vec<AnnotationSet>,
+    pub ty: Type,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -429,19 +524,32 @@ pub struct AnonymousParam {
 #[derive(Debug, PartialEq, Clone)]
 pub struct Param {
     pub annotations: Vec<AnnotationSet>,
+    pub modifiers: Vec<Modifier>,
+    pub is_property: bool,
+    pub is_mutable: bool,
     pub name: String,
     pub ty: Type,
+    pub default: Option<Expression>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct TypeParam {
     pub annotations: Vec<AnnotationSet>,
+    pub modifiers: Vec<Modifier>,
     pub name: String,
     pub bounds: Vec<TypeBound>,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct TypeConstraint {
+    pub annotations: Vec<AnnotationSet>,
+    pub name: String,
+    pub ty: Type,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct TypeBound {
+    pub annotations: Vec<AnnotationSet>,
     pub ty: Type,
     pub kind: BoundKind,
 }
@@ -480,6 +588,7 @@ pub struct Tuple {
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct VarDefinition {
+    pub annotations: Vec<AnnotationSet>,
     pub name: String,
     pub ty: Option<Type>,
 }
@@ -494,6 +603,7 @@ pub enum AnnotationSite {
     Param,
     SetParam,
     Delegate,
+    File,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -504,6 +614,10 @@ pub enum Modifier {
     Annotation,
     Sealed,
     Data,
+    Value,
+    Enum,
+    Companion,
+    Fun,
     Override,
     Lateinit,
     Inner,
@@ -526,4 +640,51 @@ pub enum Modifier {
     Const,
     Actual,
     Expect,
+    /// A `context(A, B)` receiver list.
+    Context(Vec<Type>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct KDoc {
+    /// The first paragraph of the comment.
+    pub summary: Option<KDocParagraph>,
+    /// The paragraphs between the summary and the first block tag.
+    pub description: Vec<KDocParagraph>,
+    pub tags: Vec<KDocTag>,
+}
+
+/// A Markdown paragraph, or a fenced code block kept as a single text span.
+#[derive(Debug, PartialEq, Clone)]
+pub struct KDocParagraph {
+    pub spans: Vec<KDocSpan>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum KDocSpan {
+    /// Markdown text, including inline code spans.
+    Text(String),
+    /// A `[name]` or `[label][name]` reference to another declaration.
+    Link {
+        label: Option<String>,
+        target: Vec<String>,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct KDocTag {
+    pub kind: KDocTagKind,
+    pub content: Vec<KDocParagraph>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum KDocTagKind {
+    Param(String),
+    Property(String),
+    Return,
+    /// `@throws` or its `@exception` alias.
+    Throws(Vec<String>),
+    See(Vec<String>),
+    Sample(Vec<String>),
+    /// Any other tag, such as `@since` or `@author`, by name.
+    Other(String),
 }
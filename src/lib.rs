@@ -1,5 +1,3 @@
 pub mod ast;
-// mod parse;
+mod parse;
 pub mod parser;
-
-// pub use parser::parser;
@@ -0,0 +1,50 @@
+use crate::{
+    ast::*,
+    parse::{
+        expression::call::call_args_parser,
+        ident::{keyword, qualified_name_parser},
+        whitespace::Pad,
+    },
+};
+use chumsky::prelude::*;
+
+/// Parses an annotation such as `@Suppress("x")`, optionally preceded by a
+/// use-site target (`@get:JvmStatic`) and possibly grouping several
+/// annotations in brackets (`@param:[Inject Named("x")]`).
+pub fn annotation_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, AnnotationSet, Error = Simple<char>> + Clone + 'a {
+    let site = choice((
+        keyword("field").to(AnnotationSite::Field),
+        keyword("property").to(AnnotationSite::Property),
+        keyword("get").to(AnnotationSite::Get),
+        keyword("setparam").to(AnnotationSite::SetParam),
+        keyword("set").to(AnnotationSite::Set),
+        keyword("receiver").to(AnnotationSite::Receiver),
+        keyword("param").to(AnnotationSite::Param),
+        keyword("delegate").to(AnnotationSite::Delegate),
+        keyword("file").to(AnnotationSite::File),
+    ))
+    .then_ignore(just(':').pad());
+
+    let annotation = qualified_name_parser()
+        .then(call_args_parser(expr).or_not())
+        .map(|(parts, args)| Annotation {
+            parts,
+            args: args.unwrap_or_default(),
+        });
+    let annotations = annotation
+        .clone()
+        .pad()
+        .repeated()
+        .at_least(1)
+        .delimited_by(just('[').pad(), just(']'))
+        .or(annotation.map(|annotation| vec![annotation]));
+
+    just('@')
+        .ignore_then(site.or_not())
+        .then(annotations)
+        .pad()
+        .map(|(site, annotations)| AnnotationSet { site, annotations })
+        .boxed()
+}
@@ -1,58 +1,170 @@
-use crate::{ast::*, parse::literal::literal_expr_parser};
+use crate::{
+    ast::*,
+    parse::{
+        ident::{ident_parser, keyword},
+        not_followed_by,
+        statement::declaration::accessor_start_parser,
+        ty::type_parser,
+        whitespace::{trivia, whitespace},
+    },
+};
 use chumsky::prelude::*;
 
-use super::{unary::unary_expr_parser, variable::variable_expr_parser};
-
+/// Parses binary expressions, from assignments down to `as` casts, following
+/// Kotlin's operator precedence.
+///
+/// Operands never consume a trailing line break, so most operators are only
+/// picked up on the same line as their left operand. `?:`, `&&`, `||` and
+/// `as` may also start a continuation line, as may every operator when
+/// `multiline` is set for expressions inside parentheses or brackets.
+///
+/// `initializer` is set for property initializers, where a name that starts
+/// an accessor, as `private` does in `var y = 0 private set`, ends the
+/// expression instead of being taken for an infix function.
 pub fn binary_op_parser<'a>(
-    expr: impl Parser<char, Expr, Error = Simple<char>> + 'a + Clone,
-) -> impl Parser<char, Expr, Error = Simple<char>> + 'a {
-    let assign_op = choice((
-        just("=").to(BinaryOperator::Assign),
-        just("+=").to(BinaryOperator::AddAssign),
-        just("-=").to(BinaryOperator::SubtractAssign),
-        just("*=").to(BinaryOperator::MultiplyAssign),
-        just("/=").to(BinaryOperator::DivideAssign),
-        just("%=").to(BinaryOperator::ModuloAssign),
-    ));
-
-    // TODO: prioritize binary ops based on precedence
-    let binary_op = choice((
-        just("as").to(BinaryOperator::As),
-        just("as?").to(BinaryOperator::AsNullable),
-        just("*").to(BinaryOperator::Multiply),
-        just("/").to(BinaryOperator::Divide),
-        just("%").to(BinaryOperator::Modulo),
-        just("+").to(BinaryOperator::Add),
-        just("-").to(BinaryOperator::Subtract),
-        just("..").to(BinaryOperator::RangeTo),
-        just("..<").to(BinaryOperator::RangeUntil),
-        just("?:").to(BinaryOperator::Elvis),
-        just("in").to(BinaryOperator::In),
-        just("!in").to(BinaryOperator::NotIn),
-        just("is").to(BinaryOperator::Is),
-        just("!is").to(BinaryOperator::IsNot),
-        just("<").to(BinaryOperator::LessThan),
-        just(">").to(BinaryOperator::GreaterThan),
-        just("<=").to(BinaryOperator::LessThanOrEqual),
-        just(">=").to(BinaryOperator::GreaterThanOrEqual),
-        just("==").to(BinaryOperator::Equal),
-        just("!=").to(BinaryOperator::NotEqual),
-        just("===").to(BinaryOperator::ReferenceEqual),
-        just("!==").to(BinaryOperator::ReferenceNotEqual),
-        just("&&").to(BinaryOperator::And),
-        just("||").to(BinaryOperator::Or),
-    ));
-
-    let literal = literal_expr_parser();
-    let variable = variable_expr_parser();
-    let unary = unary_expr_parser(expr.clone());
-
-    let atom = choice((variable, literal, unary)).boxed();
-
-    let op = binary_op.or(assign_op).padded();
-    atom.clone()
-        .then(op.then(atom).repeated())
-        .foldl(|lhs, (op, rhs)| {
-            Expr::BinaryOp(Box::new(lhs), op, Box::new(rhs))
+    operand: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    multiline: bool,
+    initializer: bool,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    let ty = type_parser(expr.clone()).map(Expression::Type);
+    let gap = trivia(multiline);
+
+    let as_op = whitespace().ignore_then(choice((
+        keyword("as").then(just('?')).to(BinaryOp::AsNullable),
+        keyword("as").to(BinaryOp::As),
+    )));
+    let as_expr = binary_level(operand, as_op, ty.clone());
+
+    let multiplicative = gap.clone().ignore_then(choice((
+        operator("*", "=").to(BinaryOp::Multiply),
+        operator("/", "=").to(BinaryOp::Divide),
+        operator("%", "=").to(BinaryOp::Modulo),
+    )));
+    let multiplicative = binary_level(as_expr.clone(), multiplicative, as_expr);
+
+    let additive = gap.clone().ignore_then(choice((
+        operator("+", "+=").to(BinaryOp::Add),
+        operator("-", "-=>").to(BinaryOp::Subtract),
+    )));
+    let additive =
+        binary_level(multiplicative.clone(), additive, multiplicative);
+
+    let range = gap.clone().ignore_then(choice((
+        just("..<").to(BinaryOp::RangeUntil),
+        operator("..", ".").to(BinaryOp::RangeTo),
+    )));
+    let range = binary_level(additive.clone(), range, additive);
+
+    let accessor = if initializer {
+        accessor_start_parser(expr.clone())
+            .rewind()
+            .or_not()
+            .try_map(|accessor, span| match accessor {
+                Some(()) => Err(Simple::custom(span, "unexpected accessor")),
+                None => Ok(()),
+            })
+            .boxed()
+    } else {
+        empty().boxed()
+    };
+    let infix = range
+        .clone()
+        .then(
+            gap.clone()
+                .ignore_then(accessor)
+                .ignore_then(ident_parser())
+                .then(range)
+                .repeated(),
+        )
+        .foldl(|lhs, (name, rhs)| binary(lhs, BinaryOperator::Infix(name), rhs))
+        .boxed();
+
+    let elvis = whitespace().ignore_then(just("?:")).to(BinaryOp::Elvis);
+    let elvis = binary_level(infix.clone(), elvis, infix);
+
+    let containment = gap
+        .clone()
+        .ignore_then(choice((
+            just('!').then(keyword("in")).to(BinaryOp::NotIn),
+            keyword("in").to(BinaryOp::In),
+        )))
+        .then(elvis.clone());
+    let type_check = gap
+        .clone()
+        .ignore_then(choice((
+            just('!').then(keyword("is")).to(BinaryOp::IsNot),
+            keyword("is").to(BinaryOp::Is),
+        )))
+        .then(ty);
+    let named_check = elvis
+        .then(containment.or(type_check).repeated())
+        .foldl(|lhs, (op, rhs)| binary(lhs, BinaryOperator::Operator(op), rhs))
+        .boxed();
+
+    let comparison = gap.clone().ignore_then(choice((
+        just("<=").to(BinaryOp::LessThanOrEqual),
+        just(">=").to(BinaryOp::GreaterThanOrEqual),
+        just('<').to(BinaryOp::LessThan),
+        just('>').to(BinaryOp::GreaterThan),
+    )));
+    let comparison = binary_level(named_check.clone(), comparison, named_check);
+
+    let equality = gap.clone().ignore_then(choice((
+        just("===").to(BinaryOp::ReferenceEqual),
+        just("!==").to(BinaryOp::ReferenceNotEqual),
+        operator("==", "=").to(BinaryOp::Equal),
+        operator("!=", "=").to(BinaryOp::NotEqual),
+    )));
+    let equality = binary_level(comparison.clone(), equality, comparison);
+
+    let and = whitespace().ignore_then(just("&&")).to(BinaryOp::And);
+    let conjunction = binary_level(equality.clone(), and, equality);
+
+    let or = whitespace().ignore_then(just("||")).to(BinaryOp::Or);
+    let disjunction = binary_level(conjunction.clone(), or, conjunction);
+
+    let assign_op = gap.ignore_then(choice((
+        just("+=").to(BinaryOp::AddAssign),
+        just("-=").to(BinaryOp::SubtractAssign),
+        just("*=").to(BinaryOp::MultiplyAssign),
+        just("/=").to(BinaryOp::DivideAssign),
+        just("%=").to(BinaryOp::ModuloAssign),
+        operator("=", "=").to(BinaryOp::Assign),
+    )));
+
+    disjunction
+        .clone()
+        .then(assign_op.then(disjunction).or_not())
+        .map(|(lhs, assignment)| match assignment {
+            Some((op, rhs)) => binary(lhs, BinaryOperator::Operator(op), rhs),
+            None => lhs,
         })
 }
+
+/// Parses a left-associative chain of `lhs (op rhs)*`.
+fn binary_level<'a>(
+    lhs: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    op: impl Parser<char, BinaryOp, Error = Simple<char>> + Clone + 'a,
+    rhs: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    lhs.then(op.then(rhs).repeated())
+        .foldl(|lhs, (op, rhs)| binary(lhs, BinaryOperator::Operator(op), rhs))
+        .boxed()
+}
+
+/// Matches `symbol` unless it is the prefix of a longer operator.
+fn operator(
+    symbol: &'static str,
+    forbidden_next: &'static str,
+) -> impl Parser<char, &'static str, Error = Simple<char>> + Clone {
+    just(symbol).then_ignore(not_followed_by(forbidden_next))
+}
+
+fn binary(lhs: Expression, op: BinaryOperator, rhs: Expression) -> Expression {
+    Expression::BinaryOp(BinaryOperation {
+        lhs: Box::new(lhs),
+        op,
+        rhs: Box::new(rhs),
+    })
+}
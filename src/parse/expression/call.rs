@@ -1,23 +1,56 @@
-use crate::{ast::*, parse::ty::type_parser};
+use crate::{
+    ast::*,
+    parse::{
+        ident::ident_parser, not_followed_by, ty::type_parser, whitespace::Pad,
+    },
+};
 use chumsky::prelude::*;
 
-pub fn call_expr_parser(
-    expr: impl Parser<char, Expr, Error = Simple<char>>,
-) -> impl Parser<char, Expr, Error = Simple<char>> {
-    text::ident()
-        .then(
-            type_parser()
-                .separated_by(just(',').padded())
-                .delimited_by(just('<'), just('>'))
-                .padded()
-                .or_not(),
-        )
-        .then(
-            expr.separated_by(just(',').padded())
-                .delimited_by(just('('), just(')'))
-                .padded(),
-        )
-        .map(|((name, attributes), args)| {
-            Expr::Call(name, attributes.unwrap_or_default(), args)
-        })
+/// Parses the `<T>(args) { lambda }` suffix of a call, returning the type
+/// arguments, value arguments and trailing lambda. Either the value arguments
+/// or the trailing lambda may be omitted, but not both.
+pub fn call_suffix_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    lambda: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<
+    char,
+    (Vec<Type>, Vec<CallArg>, Option<Expression>),
+    Error = Simple<char>,
+> + Clone
+       + 'a {
+    let type_args = type_parser(expr.clone())
+        .separated_by(just(',').pad())
+        .delimited_by(just('<'), just('>').pad())
+        .or_not()
+        .map(Option::unwrap_or_default);
+
+    // A lambda on the next line is a separate statement, not an argument.
+    let lambda = not_followed_by("\r\n").ignore_then(lambda);
+    let args_and_lambda = call_args_parser(expr)
+        .then(lambda.clone().or_not())
+        .or(lambda.map(|lambda| (Vec::new(), Some(lambda))));
+
+    type_args
+        .then(args_and_lambda)
+        .map(|(type_args, (args, lambda))| (type_args, args, lambda))
+}
+
+pub fn call_args_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Vec<CallArg>, Error = Simple<char>> + Clone + 'a {
+    let name = ident_parser()
+        .pad()
+        .then_ignore(just('=').then(not_followed_by("=")))
+        .or_not();
+    let arg = name.then(just('*').pad().or_not()).then(expr).map(
+        |((name, spread), value)| CallArg {
+            name,
+            value: Box::new(value),
+            is_spread: spread.is_some(),
+        },
+    );
+
+    arg.separated_by(just(',').pad())
+        .allow_trailing()
+        .delimited_by(just('('), just(')').pad())
 }
@@ -0,0 +1,32 @@
+use crate::{
+    ast::*,
+    parse::{
+        ident::keyword,
+        statement::{control_body_parser, declaration::tuple_parser},
+        whitespace::Pad,
+    },
+};
+use chumsky::prelude::*;
+
+pub fn for_expr_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    keyword("for")
+        .pad()
+        .ignore_then(
+            tuple_parser(nested.clone())
+                .then_ignore(keyword("in").pad())
+                .then(nested)
+                .delimited_by(just('(').pad(), just(')').pad()),
+        )
+        .then(control_body_parser(stmt, expr))
+        .map(|((vars, iterable), body)| {
+            Expression::For(ForExpression {
+                vars,
+                iterable: Box::new(iterable),
+                body: Box::new(body),
+            })
+        })
+}
@@ -0,0 +1,34 @@
+use crate::{
+    ast::*,
+    parse::{ident::keyword, statement::control_body_parser, whitespace::Pad},
+};
+use chumsky::prelude::*;
+
+pub fn if_expr_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    let body = control_body_parser(stmt, expr);
+
+    keyword("if")
+        .pad()
+        .ignore_then(nested.delimited_by(just('(').pad(), just(')').pad()))
+        .then(body.clone())
+        .then(
+            // A semicolon may end the `then` branch before `else`.
+            just(';')
+                .pad()
+                .or_not()
+                .ignore_then(keyword("else").pad())
+                .ignore_then(body)
+                .or_not(),
+        )
+        .map(|((expr, then), otherwise)| {
+            Expression::If(IfExpression {
+                expr: Box::new(expr),
+                then: Box::new(then),
+                otherwise: otherwise.map(Box::new),
+            })
+        })
+}
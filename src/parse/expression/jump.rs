@@ -0,0 +1,42 @@
+use crate::{
+    ast::*,
+    parse::{
+        ident::{ident_parser, keyword},
+        not_followed_by,
+        whitespace::{inline_whitespace, Pad},
+    },
+};
+use chumsky::prelude::*;
+
+pub fn jump_expr_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    let label = just('@').ignore_then(ident_parser()).or_not();
+
+    choice((
+        keyword("throw")
+            .pad()
+            .ignore_then(expr.clone())
+            .map(|expr| {
+                Expression::Throw(ThrowExpression {
+                    expr: Box::new(expr),
+                })
+            }),
+        keyword("return")
+            .ignore_then(label.clone())
+            .then_ignore(inline_whitespace())
+            .then(not_followed_by("\r\n").ignore_then(expr).or_not())
+            .map(|(label, expr)| {
+                Expression::Return(ReturnExpression {
+                    label,
+                    expr: expr.map(Box::new),
+                })
+            }),
+        keyword("break")
+            .ignore_then(label.clone())
+            .map(|label| Expression::Break(BreakExpression { label })),
+        keyword("continue")
+            .ignore_then(label)
+            .map(|label| Expression::Continue(ContinueExpression { label })),
+    ))
+}
@@ -0,0 +1,48 @@
+use crate::{
+    ast::*,
+    parse::{
+        ident::ident_parser, statement::declaration::var_parser,
+        ty::type_parser, whitespace::Pad,
+    },
+};
+use chumsky::prelude::*;
+
+/// Parses a lambda literal such as `label@{ a, (b, c): Pair<B, C> -> ... }`.
+///
+/// The parameter list is optional; a lambda without `->` has no declared
+/// parameters, even though it may refer to the implicit `it`.
+pub fn lambda_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, LambdaBlock, Error = Simple<char>> + Clone + 'a {
+    let destructured = var_parser(expr.clone())
+        .separated_by(just(',').pad())
+        .allow_trailing()
+        .delimited_by(just('(').pad(), just(')').pad())
+        .then(
+            just(':')
+                .pad()
+                .ignore_then(type_parser(expr.clone()))
+                .or_not(),
+        )
+        .map(|(vars, ty)| LambdaParam::Destructured { vars, ty });
+
+    let params = destructured
+        .or(var_parser(expr.clone()).map(LambdaParam::Var))
+        .separated_by(just(',').pad())
+        .allow_trailing()
+        .then_ignore(just("->").pad());
+
+    ident_parser()
+        .then_ignore(just('@'))
+        .or_not()
+        .then_ignore(just('{').pad())
+        .then(params.or_not())
+        .then(stmt.repeated())
+        .then_ignore(just('}').pad())
+        .map(|((label, params), statements)| LambdaBlock {
+            label,
+            params: params.unwrap_or_default(),
+            body: Some(Block { statements }),
+        })
+}
@@ -1,66 +1,889 @@
-use crate::ast::*;
+use crate::{
+    ast::*,
+    parse::{
+        ident::{ident_parser, keyword},
+        ty::type_parser,
+        whitespace::Pad,
+    },
+};
 use binary_op::binary_op_parser;
-use call::call_expr_parser;
 use chumsky::prelude::*;
+use for_expr::for_expr_parser;
+use if_expr::if_expr_parser;
+use jump::jump_expr_parser;
+use lambda::lambda_parser;
+use object_expr::object_expr_parser;
+use postfix::postfix_expr_parser;
+use reference::{callable_reference_expr_parser, reference_expr_parser};
+use try_expr::try_expr_parser;
+use unary::unary_expr_parser;
+use when_expr::when_expr_parser;
+use while_expr::while_expr_parser;
+
+use super::{
+    literal::literal_expr_parser,
+    statement::declaration::anonymous_function_parser,
+};
 
 mod binary_op;
-mod call;
+pub mod call;
+mod for_expr;
+mod if_expr;
+mod jump;
+mod lambda;
+mod object_expr;
+mod postfix;
+mod reference;
+mod try_expr;
 mod unary;
-mod variable;
+mod when_expr;
+mod while_expr;
+
+type ExprParser<'a> = Recursive<'a, char, Expression, Simple<char>>;
+
+/// Builds the parser for expressions in statement position, where a line
+/// break ends the expression unless the next line starts with a continuation
+/// operator, along with the one for expressions inside parentheses and
+/// brackets, where line breaks are insignificant, and the one for property
+/// initializers, which also ends before an accessor on the same line.
+pub fn expr_parsers<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+) -> (ExprParser<'a>, ExprParser<'a>, ExprParser<'a>) {
+    let mut expr = Recursive::declare();
+    let mut nested = Recursive::declare();
+    let mut init = Recursive::declare();
+
+    let operand = operand_parser(
+        stmt.clone(),
+        expr.clone(),
+        nested.clone(),
+        init.clone(),
+        false,
+    );
+    expr.define(binary_op_parser(
+        operand.clone(),
+        expr.clone(),
+        false,
+        false,
+    ));
+    init.define(binary_op_parser(operand, expr.clone(), false, true));
+
+    let operand =
+        operand_parser(stmt, expr.clone(), nested.clone(), init.clone(), true);
+    nested.define(binary_op_parser(operand, nested.clone(), true, false));
 
-pub fn expr_parser() -> impl Parser<char, Expr, Error = Simple<char>> {
-    recursive(|expr| {
-        choice((call_expr_parser(expr.clone()), binary_op_parser(expr)))
-            .padded()
-    })
+    (expr, nested, init)
+}
+
+/// Builds the operands of binary expressions. `expr` parses expressions in
+/// statement position and `nested` those inside parentheses and brackets,
+/// where line breaks are insignificant. `multiline` selects which of the two
+/// the operands belong to.
+fn operand_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: ExprParser<'a>,
+    nested: ExprParser<'a>,
+    init: ExprParser<'a>,
+    multiline: bool,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    let current = if multiline {
+        nested.clone()
+    } else {
+        expr.clone()
+    };
+
+    let parenthesized = nested
+        .clone()
+        .delimited_by(just('(').pad(), just(')').pad())
+        .map(|expr| {
+            Expression::Parenthesized(ParenthesizedExpression {
+                expr: Box::new(expr),
+            })
+        });
+    let lambda =
+        lambda_parser(stmt.clone(), expr.clone()).map(Expression::Lambda);
+    let collection = nested
+        .clone()
+        .separated_by(just(',').pad())
+        .allow_trailing()
+        .delimited_by(just('[').pad(), just(']').pad())
+        .map(Expression::CollectionLiteral);
+    let label = just('@').ignore_then(ident_parser()).or_not();
+    let this = keyword("this")
+        .ignore_then(label.clone())
+        .map(|label| Expression::This(ThisExpression { label }));
+    let super_ = keyword("super")
+        .ignore_then(
+            type_parser(nested.clone())
+                .delimited_by(just('<'), just('>'))
+                .or_not(),
+        )
+        .then(label)
+        .map(|(type_arg, label)| {
+            Expression::Super(SuperExpression { label, type_arg })
+        });
+
+    let atom = choice((
+        if_expr_parser(stmt.clone(), current.clone(), nested.clone()),
+        when_expr_parser(stmt.clone(), expr.clone(), nested.clone()),
+        while_expr_parser(stmt.clone(), current.clone(), nested.clone()),
+        for_expr_parser(stmt.clone(), current.clone(), nested.clone()),
+        try_expr_parser(stmt.clone(), expr.clone()),
+        object_expr_parser(stmt.clone(), expr.clone(), nested.clone(), init),
+        anonymous_function_parser(stmt, current.clone())
+            .map(Expression::Function),
+        jump_expr_parser(current.clone()),
+        literal_expr_parser(current.clone()),
+        this,
+        super_,
+        lambda.clone(),
+        callable_reference_expr_parser(current.clone()),
+        reference_expr_parser(),
+        parenthesized,
+        collection,
+    ))
+    .pad()
+    .boxed();
+
+    let postfix = postfix_expr_parser(atom, nested, lambda);
+    unary_expr_parser(postfix, current).boxed()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse::statement::stmt_parser;
+
+    fn parse(source: &str) -> Result<Expression, Vec<Simple<char>>> {
+        expr_parsers(stmt_parser())
+            .0
+            .then_ignore(end())
+            .parse(source)
+    }
+
+    fn reference(name: &str) -> Expression {
+        Expression::Reference(ReferenceExpression {
+            parts: vec![name.to_string()],
+        })
+    }
+
+    fn simple_type(name: &str, is_nullable: bool) -> Type {
+        Type::Simple(Box::new(SimpleType {
+            qualifier: None,
+            name: Some(name.to_string()),
+            type_args: vec![],
+            is_nullable,
+        }))
+    }
 
     #[test]
     fn parse_binary_expr() {
-        println!("{:#?}", expr_parser().parse("1 + 2 * 3").unwrap());
+        assert_eq!(
+            parse("a = b"),
+            Ok(Expression::BinaryOp(BinaryOperation {
+                lhs: Box::new(reference("a")),
+                op: BinaryOperator::Operator(BinaryOp::Assign),
+                rhs: Box::new(reference("b")),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_operator_precedence() {
+        let binary = |lhs, op, rhs| {
+            Expression::BinaryOp(BinaryOperation {
+                lhs: Box::new(lhs),
+                op: BinaryOperator::Operator(op),
+                rhs: Box::new(rhs),
+            })
+        };
 
-        // assert_eq!(
-        //     expr_parser().parse("1 + 2 - 3"),
-        //     Ok(Expr::BinaryOp(
-        //         Box::new(Expr::Literal(Literal::Integer(1))),
-        //         BinaryOperator::Add,
-        //         Box::new(Expr::BinaryOp(
-        //             Box::new(Expr::Literal(Literal::Integer(2))),
-        //             BinaryOperator::Subtract,
-        //             Box::new(Expr::Literal(Literal::Integer(3)))
-        //         ))
-        //     ))
-        // );
+        assert_eq!(
+            parse("a + b * c == d && e"),
+            Ok(binary(
+                binary(
+                    binary(
+                        reference("a"),
+                        BinaryOp::Add,
+                        binary(
+                            reference("b"),
+                            BinaryOp::Multiply,
+                            reference("c")
+                        ),
+                    ),
+                    BinaryOp::Equal,
+                    reference("d"),
+                ),
+                BinaryOp::And,
+                reference("e"),
+            ))
+        );
+        assert_eq!(
+            parse("a shl b + c"),
+            Ok(Expression::BinaryOp(BinaryOperation {
+                lhs: Box::new(reference("a")),
+                op: BinaryOperator::Infix("shl".to_string()),
+                rhs: Box::new(binary(
+                    reference("b"),
+                    BinaryOp::Add,
+                    reference("c")
+                )),
+            }))
+        );
+        assert_eq!(
+            parse("a as? Int ?: b"),
+            Ok(binary(
+                binary(
+                    reference("a"),
+                    BinaryOp::AsNullable,
+                    Expression::Type(simple_type("Int", false)),
+                ),
+                BinaryOp::Elvis,
+                reference("b"),
+            ))
+        );
+        assert_eq!(
+            parse("a is String?\n    || b !in c..d"),
+            Ok(binary(
+                binary(
+                    reference("a"),
+                    BinaryOp::Is,
+                    Expression::Type(simple_type("String", true)),
+                ),
+                BinaryOp::Or,
+                binary(
+                    reference("b"),
+                    BinaryOp::NotIn,
+                    binary(reference("c"), BinaryOp::RangeTo, reference("d")),
+                ),
+            ))
+        );
+        assert!(parse("a\n+ b").is_err());
     }
 
     #[test]
     fn parse_function_call() {
+        let arg = |name| CallArg {
+            name: None,
+            value: Box::new(reference(name)),
+            is_spread: false,
+        };
+
         assert_eq!(
-            expr_parser().parse("mapOf<String, Int?>(a, b, c)"),
-            Ok(Expr::Call(
-                "mapOf".to_string(),
-                vec![
-                    Type {
-                        kind: TypeKind::String,
-                        nullable: false,
-                        attributes: vec![],
+            parse("mapOf<String, Int?>(a, b, c)"),
+            Ok(Expression::Call(CallExpression {
+                expr: Box::new(reference("mapOf")),
+                args: vec![arg("a"), arg("b"), arg("c")],
+                type_args: vec![
+                    simple_type("String", false),
+                    simple_type("Int", true)
+                ],
+                lambda: None,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_call_arguments() {
+        let lambda = Expression::Lambda(LambdaBlock {
+            label: None,
+            params: vec![LambdaParam::Var(VarDefinition {
+                annotations: Vec::new(),
+                name: "x".to_string(),
+                ty: None,
+            })],
+            body: Some(Block {
+                statements: vec![Statement::Expression(reference("x"))],
+            }),
+        });
+
+        assert_eq!(
+            parse("foo<Int>(a, name = b, *arr) { x -> x }"),
+            Ok(Expression::Call(CallExpression {
+                expr: Box::new(reference("foo")),
+                args: vec![
+                    CallArg {
+                        name: None,
+                        value: Box::new(reference("a")),
+                        is_spread: false,
+                    },
+                    CallArg {
+                        name: Some("name".to_string()),
+                        value: Box::new(reference("b")),
+                        is_spread: false,
+                    },
+                    CallArg {
+                        name: None,
+                        value: Box::new(reference("arr")),
+                        is_spread: true,
+                    },
+                ],
+                type_args: vec![simple_type("Int", false)],
+                lambda: Some(Box::new(lambda)),
+            }))
+        );
+        assert_eq!(
+            parse("a.run { }"),
+            Ok(Expression::Call(CallExpression {
+                expr: Box::new(Expression::BinaryOp(BinaryOperation {
+                    lhs: Box::new(reference("a")),
+                    op: BinaryOperator::Operator(BinaryOp::Dot),
+                    rhs: Box::new(reference("run")),
+                })),
+                args: vec![],
+                type_args: vec![],
+                lambda: Some(Box::new(Expression::Lambda(LambdaBlock {
+                    label: None,
+                    params: vec![],
+                    body: Some(Block { statements: vec![] }),
+                }))),
+            }))
+        );
+        assert!(matches!(
+            parse("foo(a == b)"),
+            Ok(Expression::Call(CallExpression { args, .. }))
+                if args[0].name.is_none()
+        ));
+    }
+
+    #[test]
+    fn parse_lambda() {
+        let var = |name: &str| VarDefinition {
+            annotations: Vec::new(),
+            name: name.to_string(),
+            ty: None,
+        };
+        let pair = Type::Simple(Box::new(SimpleType {
+            qualifier: None,
+            name: Some("Pair".to_string()),
+            type_args: vec![],
+            is_nullable: false,
+        }));
+
+        assert_eq!(
+            parse("{ a, (b, c): Pair -> a }"),
+            Ok(Expression::Lambda(LambdaBlock {
+                label: None,
+                params: vec![
+                    LambdaParam::Var(var("a")),
+                    LambdaParam::Destructured {
+                        vars: vec![var("b"), var("c")],
+                        ty: Some(pair),
                     },
-                    Type {
-                        kind: TypeKind::Int,
-                        nullable: true,
-                        attributes: vec![],
-                    }
                 ],
-                vec![
-                    Expr::Variable("a".to_string()),
-                    Expr::Variable("b".to_string()),
-                    Expr::Variable("c".to_string())
-                ]
+                body: Some(Block {
+                    statements: vec![Statement::Expression(reference("a"))],
+                }),
+            }))
+        );
+        assert_eq!(
+            parse("run@{ return@run }"),
+            Ok(Expression::Lambda(LambdaBlock {
+                label: Some("run".to_string()),
+                params: vec![],
+                body: Some(Block {
+                    statements: vec![Statement::Expression(
+                        Expression::Return(ReturnExpression {
+                            label: Some("run".to_string()),
+                            expr: None,
+                        })
+                    )],
+                }),
+            }))
+        );
+        assert_eq!(parse("{ }"), parse("{ -> }"));
+        assert!(matches!(
+            parse("{ -> }"),
+            Ok(Expression::Lambda(LambdaBlock {
+                body: Some(Block { statements }),
+                ..
+            })) if statements.is_empty()
+        ));
+        assert!(matches!(
+            parse("{ -> it }"),
+            Ok(Expression::Lambda(LambdaBlock { params, .. }))
+                if params.is_empty()
+        ));
+        assert!(matches!(
+            parse("list.forEach lit@{ it }"),
+            Ok(Expression::Call(CallExpression {
+                lambda: Some(_),
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn parse_when_expr() {
+        let Ok(Expression::When(when)) = parse(
+            "when (val x = compute()) {
+                is Foo -> a; in 1..10, !in set -> b
+                is Bar if x > 0 -> { c }
+                else -> d
+            }",
+        ) else {
+            panic!("expected a when expression");
+        };
+
+        assert_eq!(
+            when.var,
+            Some(VarDefinition {
+                annotations: Vec::new(),
+                name: "x".to_string(),
+                ty: None,
+            })
+        );
+        assert!(matches!(
+            when.expr.as_deref(),
+            Some(Expression::Call(CallExpression { .. }))
+        ));
+        assert_eq!(when.entries.len(), 4);
+        assert_eq!(
+            when.entries[0],
+            WhenEntry {
+                conditions: vec![WhenCondition::Is(simple_type("Foo", false))],
+                guard: None,
+                body: Box::new(reference("a")),
+            }
+        );
+        assert!(matches!(
+            when.entries[1].conditions.as_slice(),
+            [WhenCondition::In(_), WhenCondition::NotIn(_)]
+        ));
+        assert!(matches!(
+            &when.entries[2],
+            WhenEntry {
+                guard: Some(Expression::BinaryOp(_)),
+                body,
+                ..
+            } if matches!(**body, Expression::Block(_))
+        ));
+        assert_eq!(
+            when.entries[3],
+            WhenEntry {
+                conditions: vec![],
+                guard: None,
+                body: Box::new(reference("d")),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_try_expr() {
+        let Ok(Expression::Try(expr)) =
+            parse("try { a } catch (e: IOException) { b } finally { c }")
+        else {
+            panic!("expected a try expression");
+        };
+
+        assert_eq!(expr.catches.len(), 1);
+        assert_eq!(expr.catches[0].param.name, "e");
+        assert_eq!(expr.catches[0].param.ty, simple_type("IOException", false));
+        assert_eq!(
+            expr.finally,
+            Some(Block {
+                statements: vec![Statement::Expression(reference("c"))],
+            })
+        );
+        assert!(parse("try { a }").is_err());
+    }
+
+    #[test]
+    fn parse_jump_expr() {
+        assert_eq!(
+            parse("throw e"),
+            Ok(Expression::Throw(ThrowExpression {
+                expr: Box::new(reference("e")),
+            }))
+        );
+        assert_eq!(
+            parse("return@label x"),
+            Ok(Expression::Return(ReturnExpression {
+                label: Some("label".to_string()),
+                expr: Some(Box::new(reference("x"))),
+            }))
+        );
+        assert_eq!(
+            parse("outer@ for (x in xs) break@outer"),
+            Ok(Expression::Labeled(LabeledExpression {
+                label: "outer".to_string(),
+                expr: Box::new(Expression::For(ForExpression {
+                    vars: Tuple {
+                        is_destructured: false,
+                        vars: vec![VarDefinition {
+                            annotations: Vec::new(),
+                            name: "x".to_string(),
+                            ty: None,
+                        }],
+                    },
+                    iterable: Box::new(reference("xs")),
+                    body: Box::new(Expression::Break(BreakExpression {
+                        label: Some("outer".to_string()),
+                    })),
+                })),
+            }))
+        );
+        assert_eq!(
+            parse("continue@outer"),
+            Ok(Expression::Continue(ContinueExpression {
+                label: Some("outer".to_string()),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_object_expr() {
+        let Ok(Expression::Object(object)) =
+            parse("object : Runnable, Base(1) { override fun run() {} }")
+        else {
+            panic!("expected an object expression");
+        };
+
+        assert_eq!(object.extends.len(), 2);
+        assert_eq!(object.extends[0].ty, simple_type("Runnable", false));
+        assert_eq!(object.extends[1].args.as_ref().map(Vec::len), Some(1));
+        assert!(matches!(
+            &object.inner[..],
+            [Declaration {
+                kind: DeclarationKind::Function(FunctionDeclaration {
+                    name: Some(name),
+                    ..
+                }),
+                ..
+            }] if name == "run"
+        ));
+    }
+
+    #[test]
+    fn parse_anonymous_function() {
+        let Ok(Expression::Function(function)) =
+            parse("fun(x: Int): Int { return x }")
+        else {
+            panic!("expected an anonymous function");
+        };
+
+        assert_eq!(function.name, None);
+        assert_eq!(function.params.len(), 1);
+        assert_eq!(function.return_ty, Some(simple_type("Int", false)));
+        assert!(function.body.is_some());
+    }
+
+    #[test]
+    fn parse_callable_references() {
+        let property = |lhs: Option<Expression>, name| {
+            Expression::PropertyReference(PropertyReferenceExpression {
+                lhs: lhs.map(Box::new),
+                rhs: Box::new(reference(name)),
+            })
+        };
+        let class_literal = |lhs| {
+            Expression::ClassLiteral(ClassLiteralExpression {
+                lhs: Box::new(lhs),
+            })
+        };
+
+        assert_eq!(parse("::foo"), Ok(property(None, "foo")));
+        assert_eq!(
+            parse("String::length"),
+            Ok(property(Some(reference("String")), "length"))
+        );
+        assert_eq!(
+            parse("this::bar"),
+            Ok(property(
+                Some(Expression::This(ThisExpression { label: None })),
+                "bar"
             ))
-        )
+        );
+        assert_eq!(parse("Foo::class"), Ok(class_literal(reference("Foo"))));
+        assert_eq!(
+            parse("Foo::class.java"),
+            Ok(Expression::BinaryOp(BinaryOperation {
+                lhs: Box::new(class_literal(reference("Foo"))),
+                op: BinaryOperator::Operator(BinaryOp::Dot),
+                rhs: Box::new(reference("java")),
+            }))
+        );
+        assert_eq!(
+            parse("String?::length"),
+            Ok(property(
+                Some(Expression::Type(simple_type("String", true))),
+                "length"
+            ))
+        );
+        assert!(parse("::class").is_err());
+
+        let statements = stmt_parser()
+            .repeated()
+            .then_ignore(end())
+            .parse("foo\n::bar");
+        assert!(matches!(
+            statements.as_deref(),
+            Ok([
+                Statement::Expression(Expression::Reference(_)),
+                Statement::Expression(Expression::PropertyReference(
+                    PropertyReferenceExpression { lhs: None, .. }
+                )),
+            ])
+        ));
+    }
+
+    #[test]
+    fn parse_postfix_chain() {
+        let member = |lhs, op, name| {
+            Expression::BinaryOp(BinaryOperation {
+                lhs: Box::new(lhs),
+                op: BinaryOperator::Operator(op),
+                rhs: Box::new(reference(name)),
+            })
+        };
+        let postfix = |op, expr| {
+            Expression::UnaryOp(UnaryOperation {
+                op,
+                expr: Box::new(expr),
+                is_prefix: false,
+            })
+        };
+
+        let bar = member(reference("foo"), BinaryOp::DotSafe, "bar");
+        let baz = member(
+            postfix(UnaryOperator::NullDeref, bar),
+            BinaryOp::Dot,
+            "baz",
+        );
+        let index = Expression::ArrayAccess(ArrayAccessExpression {
+            expr: Box::new(baz),
+            index: vec![Expression::Literal(Literal::Integer(NumberLiteral {
+                value: 0,
+                text: "0".to_string(),
+                radix: Radix::Decimal,
+                suffix: None,
+            }))],
+        });
+        let call = Expression::Call(CallExpression {
+            expr: Box::new(index),
+            args: vec![],
+            type_args: vec![],
+            lambda: None,
+        });
+        assert_eq!(
+            parse("foo?.bar!!.baz[0]().qux++"),
+            Ok(postfix(
+                UnaryOperator::Increment,
+                member(call, BinaryOp::Dot, "qux"),
+            ))
+        );
+
+        assert_eq!(
+            parse("-a--"),
+            Ok(Expression::UnaryOp(UnaryOperation {
+                op: UnaryOperator::Minus,
+                expr: Box::new(postfix(
+                    UnaryOperator::Decrement,
+                    reference("a")
+                )),
+                is_prefix: true,
+            }))
+        );
+        assert_eq!(
+            parse("a\n    .b"),
+            Ok(member(reference("a"), BinaryOp::Dot, "b"))
+        );
+        assert!(parse("a\n[0]").is_err());
+    }
+
+    #[test]
+    fn parse_this_and_super() {
+        let member = |lhs, name: &str| {
+            Expression::BinaryOp(BinaryOperation {
+                lhs: Box::new(lhs),
+                op: BinaryOperator::Operator(BinaryOp::Dot),
+                rhs: Box::new(reference(name)),
+            })
+        };
+        let call = |expr, args| {
+            Expression::Call(CallExpression {
+                expr: Box::new(expr),
+                args,
+                type_args: vec![],
+                lambda: None,
+            })
+        };
+        let super_ = |type_arg, label: Option<&str>| {
+            Expression::Super(SuperExpression {
+                label: label.map(str::to_string),
+                type_arg,
+            })
+        };
+
+        assert_eq!(
+            parse("this@Outer.x"),
+            Ok(member(
+                Expression::This(ThisExpression {
+                    label: Some("Outer".to_string()),
+                }),
+                "x",
+            ))
+        );
+        assert_eq!(
+            parse("super.onCreate(state)"),
+            Ok(call(
+                member(super_(None, None), "onCreate"),
+                vec![CallArg {
+                    name: None,
+                    value: Box::new(reference("state")),
+                    is_spread: false,
+                }],
+            ))
+        );
+        assert_eq!(
+            parse("super<Base>.f()"),
+            Ok(call(
+                member(super_(Some(simple_type("Base", false)), None), "f"),
+                vec![],
+            ))
+        );
+        assert_eq!(
+            parse("super@Outer.f()"),
+            Ok(call(member(super_(None, Some("Outer")), "f"), vec![]))
+        );
+    }
+
+    #[test]
+    fn parse_annotations() {
+        let annotation = |site, names: &[&str], args| AnnotationSet {
+            site,
+            annotations: vec![Annotation {
+                parts: names.iter().map(|name| name.to_string()).collect(),
+                args,
+            }],
+        };
+        let arg = |value| CallArg {
+            name: None,
+            value: Box::new(value),
+            is_spread: false,
+        };
+        let string = |value: &str| {
+            Expression::Literal(Literal::String(value.to_string()))
+        };
+
+        assert_eq!(
+            parse("@Suppress(\"x\") foo"),
+            Ok(Expression::Annotated(AnnotatedExpression {
+                annotations: vec![annotation(
+                    None,
+                    &["Suppress"],
+                    vec![arg(string("x"))]
+                )],
+                expr: Box::new(reference("foo")),
+            }))
+        );
+        assert_eq!(
+            parse("@com.foo.Bar @get:JvmStatic foo"),
+            Ok(Expression::Annotated(AnnotatedExpression {
+                annotations: vec![
+                    annotation(None, &["com", "foo", "Bar"], vec![]),
+                    annotation(
+                        Some(AnnotationSite::Get),
+                        &["JvmStatic"],
+                        vec![]
+                    ),
+                ],
+                expr: Box::new(reference("foo")),
+            }))
+        );
+        assert_eq!(
+            parse("@param:[Inject Named(\"x\")] foo"),
+            Ok(Expression::Annotated(AnnotatedExpression {
+                annotations: vec![AnnotationSet {
+                    site: Some(AnnotationSite::Param),
+                    annotations: vec![
+                        Annotation {
+                            parts: vec!["Inject".to_string()],
+                            args: vec![],
+                        },
+                        Annotation {
+                            parts: vec!["Named".to_string()],
+                            args: vec![arg(string("x"))],
+                        },
+                    ],
+                }],
+                expr: Box::new(reference("foo")),
+            }))
+        );
+
+        let Ok(Expression::Annotated(annotated)) = parse("@Ann([1, 2]) foo")
+        else {
+            panic!("expected an annotated expression");
+        };
+        assert!(matches!(
+            &*annotated.annotations[0].annotations[0].args[0].value,
+            Expression::CollectionLiteral(items) if items.len() == 2
+        ));
+
+        let Ok(Expression::BinaryOp(cast)) = parse("a as List<@A String>")
+        else {
+            panic!("expected a cast");
+        };
+        let Expression::Type(Type::Simple(ty)) = &*cast.rhs else {
+            panic!("expected a type");
+        };
+        assert_eq!(
+            ty.type_args[0].annotations,
+            vec![annotation(None, &["A"], vec![])]
+        );
+
+        let Ok(Expression::Lambda(lambda)) = parse("{ @A x -> x }") else {
+            panic!("expected a lambda");
+        };
+        let [LambdaParam::Var(var)] = lambda.params.as_slice() else {
+            panic!("expected a single parameter");
+        };
+        assert_eq!(var.annotations, vec![annotation(None, &["A"], vec![])]);
+    }
+
+    #[test]
+    fn parse_identifiers() {
+        for name in [
+            "index", "isValid", "value", "data", "open", "field", "get", "by",
+            "where", "import", "π", "naïve", "_",
+        ] {
+            assert_eq!(parse(name), Ok(reference(name)));
+        }
+        assert_eq!(parse("`when`"), Ok(reference("when")));
+        assert_eq!(
+            parse("`test name with spaces`"),
+            Ok(reference("test name with spaces"))
+        );
+        assert_eq!(
+            parse("a in index"),
+            Ok(Expression::BinaryOp(BinaryOperation {
+                lhs: Box::new(reference("a")),
+                op: BinaryOperator::Operator(BinaryOp::In),
+                rhs: Box::new(reference("index")),
+            }))
+        );
+        assert_eq!(
+            parse("\"$größe\""),
+            Ok(Expression::StringTemplate(vec![
+                StringTemplateExpression::Simple("größe".to_string())
+            ]))
+        );
+
+        assert!(parse("when").is_err());
+        assert!(parse("`a\nb`").is_err());
+    }
+
+    #[test]
+    fn parse_if_expr() {
+        assert_eq!(
+            parse("if (a) b else c"),
+            Ok(Expression::If(IfExpression {
+                expr: Box::new(reference("a")),
+                then: Box::new(reference("b")),
+                otherwise: Some(Box::new(reference("c"))),
+            }))
+        );
+        assert_eq!(parse("if (a) b; else c"), parse("if (a) b else c"));
+        assert!(parse("if (a) b;").is_err());
     }
 }
@@ -0,0 +1,40 @@
+use crate::{
+    ast::*,
+    parse::{
+        ident::keyword,
+        statement::declaration::{
+            declaration_parser, member_parser, super_types_parser,
+        },
+        whitespace::Pad,
+    },
+};
+use chumsky::prelude::*;
+
+pub fn object_expr_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    init: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    let member = member_parser(
+        stmt.clone(),
+        nested.clone(),
+        declaration_parser(stmt, expr.clone(), nested.clone(), init),
+    );
+    let body = member
+        .then_ignore(just(';').pad().repeated())
+        .repeated()
+        .delimited_by(just('{').pad(), just('}').pad());
+
+    keyword("object")
+        .pad()
+        .ignore_then(super_types_parser(expr, nested).or_not())
+        .then(body.or_not())
+        .map(|(extends, inner)| {
+            Expression::Object(ObjectExpression {
+                annotations: Vec::new(),
+                extends: extends.unwrap_or_default(),
+                inner: inner.unwrap_or_default(),
+            })
+        })
+}
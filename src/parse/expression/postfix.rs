@@ -0,0 +1,106 @@
+use crate::{
+    ast::*,
+    parse::{
+        ident::ident_parser,
+        not_followed_by,
+        whitespace::{inline_whitespace, whitespace, Pad},
+    },
+};
+use chumsky::prelude::*;
+
+use super::{
+    call::call_suffix_parser,
+    reference::{callable_reference, callable_reference_suffix_parser},
+};
+
+enum Suffix {
+    Unary(UnaryOperator),
+    Navigation(BinaryOp, String),
+    Index(Vec<Expression>),
+    CallableReference(Option<String>),
+    Call(Vec<Type>, Vec<CallArg>, Option<Expression>),
+}
+
+/// Parses an atom followed by any number of postfix operators, member
+/// accesses, indexing and call suffixes, folding them from left to right.
+///
+/// Only `.` and `?.` may start on a new line; every other suffix has to
+/// follow its receiver on the same line.
+pub fn postfix_expr_parser<'a>(
+    atom: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    lambda: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    let unary = choice((
+        just("++").to(UnaryOperator::Increment),
+        just("--").to(UnaryOperator::Decrement),
+        just("!!").to(UnaryOperator::NullDeref),
+    ))
+    .map(Suffix::Unary);
+
+    let navigation = whitespace()
+        .ignore_then(choice((
+            just("?.").to(BinaryOp::DotSafe),
+            just('.')
+                .then_ignore(not_followed_by("."))
+                .to(BinaryOp::Dot),
+        )))
+        .then(ident_parser().pad())
+        .map(|(op, name)| Suffix::Navigation(op, name));
+
+    let reference =
+        callable_reference_suffix_parser().map(Suffix::CallableReference);
+
+    let index = expr
+        .clone()
+        .separated_by(just(',').pad())
+        .at_least(1)
+        .allow_trailing()
+        .delimited_by(just('['), just(']').pad())
+        .map(Suffix::Index);
+
+    let call = call_suffix_parser(expr, lambda)
+        .map(|(type_args, args, lambda)| Suffix::Call(type_args, args, lambda));
+
+    let suffix = choice((
+        unary.then_ignore(inline_whitespace()),
+        navigation,
+        reference,
+        index,
+        call,
+    ));
+
+    atom.then(suffix.repeated())
+        .foldl(|expr, suffix| match suffix {
+            Suffix::Unary(op) => Expression::UnaryOp(UnaryOperation {
+                op,
+                expr: Box::new(expr),
+                is_prefix: false,
+            }),
+            Suffix::Navigation(op, name) => {
+                Expression::BinaryOp(BinaryOperation {
+                    lhs: Box::new(expr),
+                    op: BinaryOperator::Operator(op),
+                    rhs: Box::new(Expression::Reference(ReferenceExpression {
+                        parts: vec![name],
+                    })),
+                })
+            }
+            Suffix::CallableReference(name) => callable_reference(expr, name),
+            Suffix::Index(index) => {
+                Expression::ArrayAccess(ArrayAccessExpression {
+                    expr: Box::new(expr),
+                    index,
+                })
+            }
+            Suffix::Call(type_args, args, lambda) => {
+                Expression::Call(CallExpression {
+                    expr: Box::new(expr),
+                    args,
+                    type_args,
+                    lambda: lambda.map(Box::new),
+                })
+            }
+        })
+        .boxed()
+}
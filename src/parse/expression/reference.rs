@@ -0,0 +1,82 @@
+use crate::{
+    ast::*,
+    parse::{
+        ident::{ident_parser, keyword},
+        ty::type_parser,
+        whitespace::{inline_whitespace, whitespace, Pad},
+    },
+};
+use chumsky::prelude::*;
+
+pub fn reference_expr_parser(
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone {
+    ident_parser().map(|name| {
+        Expression::Reference(ReferenceExpression { parts: vec![name] })
+    })
+}
+
+/// Parses a callable reference that does not start with an expression, such
+/// as `::foo` or `List<String>::size`. References on expressions like
+/// `this::bar` are parsed as postfix suffixes instead.
+pub fn callable_reference_expr_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    // Plain names are left to the postfix parser, so only receivers that
+    // cannot be parsed as expressions are accepted here.
+    let receiver_type =
+        type_parser(expr.clone()).try_map(|ty, span| match &ty {
+            Type::Simple(simple)
+                if simple.type_args.is_empty() && !simple.is_nullable =>
+            {
+                Err(Simple::custom(span, "expected a type with type arguments"))
+            }
+            _ => Ok(Expression::Type(ty)),
+        });
+
+    let unbound =
+        just("::")
+            .pad()
+            .ignore_then(ident_parser().pad())
+            .map(|name| {
+                Expression::PropertyReference(PropertyReferenceExpression {
+                    lhs: None,
+                    rhs: Box::new(Expression::Reference(ReferenceExpression {
+                        parts: vec![name],
+                    })),
+                })
+            });
+
+    receiver_type
+        .then(callable_reference_suffix_parser())
+        .map(|(lhs, rhs)| callable_reference(lhs, rhs))
+        .or(unbound)
+}
+
+/// Parses the `::name` or `::class` part of a callable reference, returning
+/// `None` for class literals. The `::` has to be on the same line as the
+/// receiver, since a line break before it ends the statement.
+pub fn callable_reference_suffix_parser(
+) -> impl Parser<char, Option<String>, Error = Simple<char>> + Clone {
+    inline_whitespace()
+        .then(just("::"))
+        .then(whitespace())
+        .ignore_then(
+            keyword("class").to(None).or(ident_parser().map(Some)).pad(),
+        )
+}
+
+pub fn callable_reference(lhs: Expression, rhs: Option<String>) -> Expression {
+    match rhs {
+        Some(name) => {
+            Expression::PropertyReference(PropertyReferenceExpression {
+                lhs: Some(Box::new(lhs)),
+                rhs: Box::new(Expression::Reference(ReferenceExpression {
+                    parts: vec![name],
+                })),
+            })
+        }
+        None => Expression::ClassLiteral(ClassLiteralExpression {
+            lhs: Box::new(lhs),
+        }),
+    }
+}
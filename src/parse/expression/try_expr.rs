@@ -0,0 +1,46 @@
+use crate::{
+    ast::*,
+    parse::{
+        ident::keyword,
+        statement::{block_parser, declaration::param_parser},
+        whitespace::Pad,
+    },
+};
+use chumsky::prelude::*;
+
+pub fn try_expr_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    let block = block_parser(stmt);
+
+    let catch = keyword("catch")
+        .pad()
+        .ignore_then(
+            param_parser(expr)
+                .then_ignore(just(',').pad().or_not())
+                .delimited_by(just('(').pad(), just(')').pad()),
+        )
+        .then(block.clone())
+        .map(|(param, body)| CatchExpression { param, body });
+    let finally = keyword("finally").pad().ignore_then(block.clone());
+
+    keyword("try")
+        .pad()
+        .ignore_then(block)
+        .then(catch.repeated())
+        .then(finally.or_not())
+        .try_map(|((body, catches), finally), span| {
+            if catches.is_empty() && finally.is_none() {
+                return Err(Simple::custom(
+                    span,
+                    "expected `catch` or `finally` after `try` block",
+                ));
+            }
+            Ok(Expression::Try(TryExpression {
+                body,
+                catches,
+                finally,
+            }))
+        })
+}
@@ -1,18 +1,64 @@
-use crate::ast::*;
+use crate::{
+    ast::*,
+    parse::{
+        annotation::annotation_parser,
+        ident::ident_parser,
+        whitespace::{whitespace, Pad},
+    },
+};
 use chumsky::prelude::*;
 
-pub fn unary_expr_parser(
-    expr: impl Parser<char, Expr, Error = Simple<char>>,
-) -> impl Parser<char, Expr, Error = Simple<char>> {
+#[derive(Clone)]
+enum Prefix {
+    Operator(UnaryOperator),
+    Label(String),
+    Annotation(AnnotationSet),
+}
+
+/// Parses prefix operators, annotations and `label@` prefixes applied to an
+/// expression. A label directly followed by `{` is left to the lambda parser.
+pub fn unary_expr_parser<'a>(
+    atom: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
     let unary_op = choice((
         just("!").to(UnaryOperator::Not),
         just("++").to(UnaryOperator::Increment),
         just("--").to(UnaryOperator::Decrement),
         just("+").to(UnaryOperator::Plus),
         just("-").to(UnaryOperator::Minus),
-    ));
+    ))
+    .map(Prefix::Operator);
+    let label = ident_parser()
+        .then_ignore(just('@'))
+        .then_ignore(whitespace().then(just('{')).not().rewind())
+        .map(Prefix::Label);
 
     unary_op
-        .then(expr)
-        .map(|(op, rhs)| Expr::UnaryOp(op, Box::new(rhs)))
+        .or(label)
+        .pad()
+        .or(annotation_parser(expr).map(Prefix::Annotation))
+        .repeated()
+        .then(atom)
+        .foldr(|prefix, expr| match prefix {
+            Prefix::Operator(op) => Expression::UnaryOp(UnaryOperation {
+                op,
+                expr: Box::new(expr),
+                is_prefix: true,
+            }),
+            Prefix::Label(label) => Expression::Labeled(LabeledExpression {
+                label,
+                expr: Box::new(expr),
+            }),
+            Prefix::Annotation(annotation) => match expr {
+                Expression::Annotated(mut annotated) => {
+                    annotated.annotations.insert(0, annotation);
+                    Expression::Annotated(annotated)
+                }
+                expr => Expression::Annotated(AnnotatedExpression {
+                    annotations: vec![annotation],
+                    expr: Box::new(expr),
+                }),
+            },
+        })
 }
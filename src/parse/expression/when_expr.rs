@@ -0,0 +1,79 @@
+use crate::{
+    ast::*,
+    parse::{
+        ident::keyword,
+        statement::{control_body_parser, declaration::var_parser},
+        ty::type_parser,
+        whitespace::Pad,
+    },
+};
+use chumsky::prelude::*;
+
+pub fn when_expr_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    let var = keyword("val")
+        .pad()
+        .ignore_then(var_parser(nested.clone()))
+        .then_ignore(just('=').pad());
+    let subject = var
+        .or_not()
+        .then(nested)
+        .delimited_by(just('(').pad(), just(')').pad());
+
+    let condition = choice((
+        just('!')
+            .then(keyword("in"))
+            .pad()
+            .ignore_then(expr.clone())
+            .map(WhenCondition::NotIn),
+        keyword("in")
+            .pad()
+            .ignore_then(expr.clone())
+            .map(WhenCondition::In),
+        just('!')
+            .then(keyword("is"))
+            .pad()
+            .ignore_then(type_parser(expr.clone()))
+            .map(WhenCondition::IsNot),
+        keyword("is")
+            .pad()
+            .ignore_then(type_parser(expr.clone()))
+            .map(WhenCondition::Is),
+        expr.clone().map(WhenCondition::Expression),
+    ));
+    let conditions = keyword("else").pad().to(Vec::new()).or(condition
+        .separated_by(just(',').pad())
+        .allow_trailing()
+        .at_least(1));
+    let guard = keyword("if").pad().ignore_then(expr.clone());
+
+    let entry = conditions
+        .then(guard.or_not())
+        .then_ignore(just("->").pad())
+        .then(control_body_parser(stmt, expr))
+        .then_ignore(just(';').pad().repeated())
+        .map(|((conditions, guard), body)| WhenEntry {
+            conditions,
+            guard,
+            body: Box::new(body),
+        });
+
+    keyword("when")
+        .pad()
+        .ignore_then(subject.or_not())
+        .then(
+            entry
+                .repeated()
+                .delimited_by(just('{').pad(), just('}').pad()),
+        )
+        .map(|(subject, entries)| {
+            let (var, expr) = match subject {
+                Some((var, expr)) => (var, Some(Box::new(expr))),
+                None => (None, None),
+            };
+            Expression::When(WhenExpression { var, expr, entries })
+        })
+}
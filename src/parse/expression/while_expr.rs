@@ -0,0 +1,37 @@
+use crate::{
+    ast::*,
+    parse::{ident::keyword, statement::control_body_parser, whitespace::Pad},
+};
+use chumsky::prelude::*;
+
+pub fn while_expr_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    let body = control_body_parser(stmt, expr);
+    let condition = keyword("while")
+        .pad()
+        .ignore_then(nested.delimited_by(just('(').pad(), just(')').pad()))
+        .boxed();
+
+    let while_loop =
+        condition.clone().then(body.clone()).map(|(expr, body)| {
+            Expression::While(WhileExpression {
+                expr: Box::new(expr),
+                body: Box::new(body),
+                is_do_while: false,
+            })
+        });
+    let do_while = keyword("do").pad().ignore_then(body).then(condition).map(
+        |(body, expr)| {
+            Expression::While(WhileExpression {
+                expr: Box::new(expr),
+                body: Box::new(body),
+                is_do_while: true,
+            })
+        },
+    );
+
+    while_loop.or(do_while)
+}
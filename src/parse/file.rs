@@ -0,0 +1,186 @@
+use crate::{
+    ast::*,
+    parse::{
+        annotation::annotation_parser,
+        expression::expr_parsers,
+        ident::{ident_parser, keyword, qualified_name_parser},
+        statement::{
+            declaration::{declaration_parser, modifiers_parser},
+            stmt_parser,
+        },
+        whitespace::{whitespace, Pad},
+    },
+};
+use chumsky::prelude::*;
+
+pub fn file_parser() -> impl Parser<char, KotlinFile, Error = Simple<char>> {
+    let stmt = stmt_parser();
+    let (expr, nested, init) = expr_parsers(stmt.clone());
+    let declaration = declaration_parser(stmt, expr, nested.clone(), init)
+        .then_ignore(just(';').pad().repeated());
+
+    let file_annotation =
+        annotation_parser(nested.clone()).try_map(|annotation, span| {
+            match annotation.site {
+                Some(AnnotationSite::File) => Ok(annotation),
+                _ => Err(Simple::custom(span, "expected a file annotation")),
+            }
+        });
+
+    file_annotation
+        .repeated()
+        .then(package_parser(nested).or_not())
+        .then(import_parser().repeated())
+        .then(declaration.repeated())
+        .then_ignore(whitespace().then(end()))
+        .map(|(((mut annotations, package), imports), declarations)| {
+            let package = package.map(|(package_annotations, package)| {
+                annotations.extend(package_annotations);
+                package
+            });
+
+            KotlinFile {
+                package,
+                imports,
+                declarations,
+                annotations,
+            }
+        })
+}
+
+/// Parses the package header together with the annotations preceding it,
+/// which belong to the file rather than the package itself.
+pub fn package_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, (Vec<AnnotationSet>, Package), Error = Simple<char>>
+       + Clone
+       + 'a {
+    modifiers_parser(expr)
+        .then_ignore(keyword("package").pad())
+        .then(qualified_name_parser().pad())
+        .then_ignore(just(';').pad().or_not())
+        .map(|((annotations, modifiers), names)| {
+            (annotations, Package { modifiers, names })
+        })
+}
+
+pub fn import_parser() -> impl Parser<char, Import, Error = Simple<char>> + Clone
+{
+    let wildcard = just('.').pad().then(just('*')).to((true, None));
+    let alias = keyword("as")
+        .pad()
+        .ignore_then(ident_parser())
+        .map(|alias| (false, Some(alias)));
+
+    keyword("import")
+        .pad()
+        .ignore_then(qualified_name_parser())
+        .then(wildcard.or(alias).or_not().map(Option::unwrap_or_default))
+        .then_ignore(just(';').pad().or_not())
+        .pad()
+        .map(|(names, (is_wildcard, alias))| Import {
+            names,
+            is_wildcard,
+            alias,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn parse_import(source: &str) -> Result<Import, Vec<Simple<char>>> {
+        import_parser().then_ignore(end()).parse(source)
+    }
+
+    #[test]
+    fn parse_package() {
+        let stmt = stmt_parser();
+        let parser = package_parser(expr_parsers(stmt).1).then_ignore(end());
+
+        assert_eq!(
+            parser.parse("@Suppress(\"unused\") package a.b.c;"),
+            Ok((
+                vec![AnnotationSet {
+                    site: None,
+                    annotations: vec![Annotation {
+                        parts: names(&["Suppress"]),
+                        args: vec![CallArg {
+                            name: None,
+                            value: Box::new(Expression::Literal(
+                                Literal::String("unused".to_string())
+                            )),
+                            is_spread: false,
+                        }],
+                    }],
+                }],
+                Package {
+                    modifiers: vec![],
+                    names: names(&["a", "b", "c"]),
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_imports() {
+        assert_eq!(
+            parse_import("import a.b.C"),
+            Ok(Import {
+                names: names(&["a", "b", "C"]),
+                is_wildcard: false,
+                alias: None,
+            })
+        );
+        assert_eq!(
+            parse_import("import a.b.*"),
+            Ok(Import {
+                names: names(&["a", "b"]),
+                is_wildcard: true,
+                alias: None,
+            })
+        );
+        assert_eq!(
+            parse_import("import a.b.C as D;"),
+            Ok(Import {
+                names: names(&["a", "b", "C"]),
+                is_wildcard: false,
+                alias: Some("D".to_string()),
+            })
+        );
+        assert!(parse_import("import a.b.* as D").is_err());
+    }
+
+    #[test]
+    fn parse_file_annotations() {
+        let file = file_parser()
+            .parse("@file:JvmName(\"Utils\")\n@Suppress(\"x\") package a\n")
+            .unwrap();
+
+        assert_eq!(
+            file.annotations
+                .iter()
+                .map(|set| (set.site.clone(), set.annotations[0].parts.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some(AnnotationSite::File), names(&["JvmName"])),
+                (None, names(&["Suppress"])),
+            ]
+        );
+        assert_eq!(
+            file.package,
+            Some(Package {
+                modifiers: vec![],
+                names: names(&["a"]),
+            })
+        );
+
+        let file = file_parser().parse("@file:A\n@B class C").unwrap();
+        assert_eq!(file.annotations.len(), 1);
+        assert_eq!(file.declarations[0].annotations.len(), 1);
+    }
+}
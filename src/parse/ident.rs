@@ -0,0 +1,152 @@
+use chumsky::prelude::*;
+
+/// Keywords that can never be used as identifiers unless escaped with
+/// backticks.
+const HARD_KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+/// Keywords that only have a meaning in specific positions, such as `by` or
+/// `get`, and are valid identifiers everywhere else.
+const SOFT_KEYWORDS: &[&str] = &[
+    "by",
+    "catch",
+    "constructor",
+    "context",
+    "delegate",
+    "dynamic",
+    "field",
+    "file",
+    "finally",
+    "get",
+    "import",
+    "init",
+    "param",
+    "property",
+    "receiver",
+    "set",
+    "setparam",
+    "where",
+];
+
+/// Keywords that are only recognized in modifier lists.
+const MODIFIER_KEYWORDS: &[&str] = &[
+    "abstract",
+    "actual",
+    "annotation",
+    "companion",
+    "const",
+    "crossinline",
+    "data",
+    "enum",
+    "expect",
+    "external",
+    "final",
+    "infix",
+    "inline",
+    "inner",
+    "internal",
+    "lateinit",
+    "noinline",
+    "open",
+    "operator",
+    "out",
+    "override",
+    "private",
+    "protected",
+    "public",
+    "reified",
+    "sealed",
+    "suspend",
+    "tailrec",
+    "value",
+    "vararg",
+];
+
+/// Parses an identifier made of Unicode letters, digits and underscores,
+/// without checking it against the keyword table.
+pub fn raw_ident_parser(
+) -> impl Parser<char, String, Error = Simple<char>> + Clone {
+    filter(|c: &char| c.is_alphabetic() || *c == '_')
+        .chain(filter(|c: &char| c.is_alphanumeric() || *c == '_').repeated())
+        .collect()
+}
+
+/// Parses a name, which is either an identifier other than a hard keyword or
+/// any text escaped with backticks, as in `` `test name` ``.
+pub fn ident_parser() -> impl Parser<char, String, Error = Simple<char>> + Clone
+{
+    let escaped = filter(|c: &char| !matches!(c, '`' | '\n' | '\r'))
+        .repeated()
+        .at_least(1)
+        .collect()
+        .delimited_by(just('`'), just('`'));
+
+    raw_ident_parser()
+        .try_map(|ident: String, span| {
+            if HARD_KEYWORDS.contains(&ident.as_str()) {
+                Err(Simple::custom(
+                    span,
+                    format!("`{}` is a reserved keyword", ident),
+                ))
+            } else {
+                Ok(ident)
+            }
+        })
+        .or(escaped)
+}
+
+/// Parses a keyword, which must not be directly followed by further
+/// identifier characters.
+pub fn keyword(
+    name: &'static str,
+) -> impl Parser<char, (), Error = Simple<char>> + Clone {
+    debug_assert!(
+        [HARD_KEYWORDS, SOFT_KEYWORDS, MODIFIER_KEYWORDS]
+            .iter()
+            .any(|keywords| keywords.contains(&name)),
+        "`{}` is not a keyword",
+        name
+    );
+
+    raw_ident_parser().try_map(move |ident, span| {
+        if ident == name {
+            Ok(())
+        } else {
+            Err(Simple::custom(span, format!("expected `{}`", name)))
+        }
+    })
+}
+
+/// Parses a dot-separated name such as `kotlin.collections.List`.
+pub fn qualified_name_parser(
+) -> impl Parser<char, Vec<String>, Error = Simple<char>> + Clone {
+    ident_parser().separated_by(just('.')).at_least(1)
+}
@@ -0,0 +1,333 @@
+use crate::{
+    ast::*,
+    parse::{
+        ident::qualified_name_parser,
+        not_followed_by,
+        whitespace::{block_comment_body, comment_parser},
+    },
+};
+use chumsky::prelude::*;
+
+/// Skips whitespace and comments, returning the last KDoc comment among
+/// them, which documents the declaration that follows.
+pub fn doc_comment_parser(
+) -> impl Parser<char, Option<Box<KDoc>>, Error = Simple<char>> + Clone {
+    let comment = just("/**")
+        .then(just('/').not().rewind())
+        .ignore_then(block_comment_body())
+        .map(Some);
+
+    choice((
+        comment,
+        filter(|c: &char| c.is_whitespace()).to(None),
+        comment_parser().to(None),
+    ))
+    .repeated()
+    .map(|docs| {
+        docs.into_iter()
+            .flatten()
+            .last()
+            .map(|text| Box::new(kdoc(&text)))
+    })
+}
+
+/// Builds the structured model of a KDoc comment from the text between its
+/// `/**` and `*/` delimiters.
+fn kdoc(text: &str) -> KDoc {
+    let mut description = Vec::new();
+    let mut tags: Vec<(KDocTagKind, Vec<String>)> = Vec::new();
+    let mut in_fence = false;
+
+    for line in text.lines().map(strip_margin) {
+        let tag = line.trim_start().strip_prefix('@').filter(|_| !in_fence);
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+
+        match tag {
+            Some(tag) => {
+                let (kind, rest) = tag_kind(tag);
+                tags.push((kind, vec![rest.to_string()]));
+            }
+            None => match tags.last_mut() {
+                Some((_, lines)) => lines.push(line.to_string()),
+                None => description.push(line.to_string()),
+            },
+        }
+    }
+
+    let spans = spans_parser();
+    let mut description = paragraphs(&description, &spans).into_iter();
+    KDoc {
+        summary: description.next(),
+        description: description.collect(),
+        tags: tags
+            .into_iter()
+            .map(|(kind, lines)| KDocTag {
+                kind,
+                content: paragraphs(&lines, &spans),
+            })
+            .collect(),
+    }
+}
+
+/// Removes the leading `*` and the space after it from a comment line.
+fn strip_margin(line: &str) -> &str {
+    let line = line.trim_start();
+    match line.strip_prefix('*') {
+        Some(rest) => rest.strip_prefix(' ').unwrap_or(rest),
+        None => line,
+    }
+    .trim_end()
+}
+
+/// Splits a block tag line, without its `@`, into the tag and the text that
+/// follows it and its subject.
+fn tag_kind(tag: &str) -> (KDocTagKind, &str) {
+    let (name, rest) = split_word(tag);
+    let (subject, content) = split_word(rest);
+    let subject = subject.trim_start_matches('[').trim_end_matches(']');
+    let path = || subject.split('.').map(str::to_string).collect();
+
+    match name {
+        "param" => (KDocTagKind::Param(subject.to_string()), content),
+        "property" => (KDocTagKind::Property(subject.to_string()), content),
+        "throws" | "exception" => (KDocTagKind::Throws(path()), content),
+        "see" => (KDocTagKind::See(path()), content),
+        "sample" => (KDocTagKind::Sample(path()), content),
+        "return" => (KDocTagKind::Return, rest),
+        _ => (KDocTagKind::Other(name.to_string()), rest),
+    }
+}
+
+fn split_word(text: &str) -> (&str, &str) {
+    let text = text.trim_start();
+    match text.find(char::is_whitespace) {
+        Some(end) => (&text[..end], text[end..].trim_start()),
+        None => (text, ""),
+    }
+}
+
+/// Groups lines into paragraphs separated by blank lines. Fenced code blocks
+/// become paragraphs of their own and are kept verbatim.
+fn paragraphs(
+    lines: &[String],
+    spans: &impl Parser<char, Vec<KDocSpan>, Error = Simple<char>>,
+) -> Vec<KDocParagraph> {
+    let mut paragraphs = Vec::new();
+    let mut text: Vec<&str> = Vec::new();
+    let mut code: Option<Vec<&str>> = None;
+
+    let flush = |text: &mut Vec<&str>, paragraphs: &mut Vec<KDocParagraph>| {
+        if !text.is_empty() {
+            let spans = spans
+                .parse(text.join("\n"))
+                .unwrap_or_else(|_| vec![KDocSpan::Text(text.join("\n"))]);
+            paragraphs.push(KDocParagraph { spans });
+            text.clear();
+        }
+    };
+
+    for line in lines {
+        let is_fence = line.trim_start().starts_with("```");
+        match &mut code {
+            Some(block) => {
+                block.push(line);
+                if is_fence {
+                    paragraphs.push(KDocParagraph {
+                        spans: vec![KDocSpan::Text(block.join("\n"))],
+                    });
+                    code = None;
+                }
+            }
+            None if is_fence => {
+                flush(&mut text, &mut paragraphs);
+                code = Some(vec![line]);
+            }
+            None if line.is_empty() => flush(&mut text, &mut paragraphs),
+            None => text.push(line),
+        }
+    }
+
+    if let Some(block) = code {
+        paragraphs.push(KDocParagraph {
+            spans: vec![KDocSpan::Text(block.join("\n"))],
+        });
+    }
+    flush(&mut text, &mut paragraphs);
+
+    paragraphs
+}
+
+/// Parses the text of a paragraph into plain text and `[links]`. Brackets
+/// inside code spans and Markdown `[text](url)` links are left as text.
+fn spans_parser() -> impl Parser<char, Vec<KDocSpan>, Error = Simple<char>> {
+    let code = just('`')
+        .chain(none_of("`").repeated())
+        .chain(just('`'))
+        .collect::<String>()
+        .map(KDocSpan::Text);
+
+    let target = qualified_name_parser().delimited_by(just('['), just(']'));
+    let label = none_of("[]")
+        .repeated()
+        .at_least(1)
+        .collect::<String>()
+        .delimited_by(just('['), just(']'));
+    let link = label
+        .then(target.clone())
+        .map(|(label, target)| KDocSpan::Link {
+            label: Some(label),
+            target,
+        })
+        .or(target.then_ignore(not_followed_by("(")).map(|target| {
+            KDocSpan::Link {
+                label: None,
+                target,
+            }
+        }));
+
+    choice((
+        code,
+        link,
+        any().map(|c: char| KDocSpan::Text(c.to_string())),
+    ))
+    .repeated()
+    .then_ignore(end())
+    .map(|spans| {
+        spans.into_iter().fold(Vec::new(), |mut spans, span| {
+            match (spans.last_mut(), span) {
+                (Some(KDocSpan::Text(text)), KDocSpan::Text(next)) => {
+                    text.push_str(&next)
+                }
+                (_, span) => spans.push(span),
+            }
+            spans
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> KDocParagraph {
+        KDocParagraph {
+            spans: vec![KDocSpan::Text(text.to_string())],
+        }
+    }
+
+    fn path(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_kdoc_sections() {
+        let doc = kdoc(
+            "
+             * Returns the sum of [a] and [b][kotlin.Int].
+             *
+             * Overflows silently,
+             * like `Int.plus`.
+             *
+             * ```
+             * add(1, 2)
+             *
+             * ```
+             * @param a the first operand.
+             * @param [b] the second operand,
+             *   spanning two lines.
+             * @return the sum.
+             * @throws kotlin.ArithmeticException never.
+             * @see subtract
+             * @sample samples.add
+             * @since 1.2
+             ",
+        );
+
+        assert_eq!(
+            doc.summary,
+            Some(KDocParagraph {
+                spans: vec![
+                    KDocSpan::Text("Returns the sum of ".to_string()),
+                    KDocSpan::Link {
+                        label: None,
+                        target: path(&["a"]),
+                    },
+                    KDocSpan::Text(" and ".to_string()),
+                    KDocSpan::Link {
+                        label: Some("b".to_string()),
+                        target: path(&["kotlin", "Int"]),
+                    },
+                    KDocSpan::Text(".".to_string()),
+                ],
+            })
+        );
+        assert_eq!(
+            doc.description,
+            vec![
+                text("Overflows silently,\nlike `Int.plus`."),
+                text("```\nadd(1, 2)\n\n```"),
+            ]
+        );
+        assert_eq!(
+            doc.tags,
+            vec![
+                KDocTag {
+                    kind: KDocTagKind::Param("a".to_string()),
+                    content: vec![text("the first operand.")],
+                },
+                KDocTag {
+                    kind: KDocTagKind::Param("b".to_string()),
+                    content: vec![text(
+                        "the second operand,\n  spanning two lines."
+                    )],
+                },
+                KDocTag {
+                    kind: KDocTagKind::Return,
+                    content: vec![text("the sum.")],
+                },
+                KDocTag {
+                    kind: KDocTagKind::Throws(path(&[
+                        "kotlin",
+                        "ArithmeticException"
+                    ])),
+                    content: vec![text("never.")],
+                },
+                KDocTag {
+                    kind: KDocTagKind::See(path(&["subtract"])),
+                    content: vec![],
+                },
+                KDocTag {
+                    kind: KDocTagKind::Sample(path(&["samples", "add"])),
+                    content: vec![],
+                },
+                KDocTag {
+                    kind: KDocTagKind::Other("since".to_string()),
+                    content: vec![text("1.2")],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn keep_markdown_links_as_text() {
+        assert_eq!(
+            kdoc(" See [the guide](https://kotlinlang.org) and `a[0]`. "),
+            KDoc {
+                summary: Some(text(
+                    "See [the guide](https://kotlinlang.org) and `a[0]`."
+                )),
+                description: vec![],
+                tags: vec![],
+            }
+        );
+        assert_eq!(
+            kdoc("\n * @property name the name.\n ").tags,
+            vec![KDocTag {
+                kind: KDocTagKind::Property("name".to_string()),
+                content: vec![text("the name.")],
+            }]
+        );
+    }
+}
@@ -1,9 +1,10 @@
-use crate::ast::Literal;
+use crate::{ast::Literal, parse::ident::keyword};
 use chumsky::prelude::*;
 
-pub fn boolean_literal() -> impl Parser<char, Literal, Error = Simple<char>> {
+pub fn boolean_literal(
+) -> impl Parser<char, Literal, Error = Simple<char>> + Clone {
     choice((
-        just("true").to(Literal::Boolean(true)),
-        just("false").to(Literal::Boolean(false)),
+        keyword("true").to(Literal::Boolean(true)),
+        keyword("false").to(Literal::Boolean(false)),
     ))
 }
@@ -1,9 +1,12 @@
 use crate::ast::Literal;
 use chumsky::prelude::*;
 
-pub fn char_literal() -> impl Parser<char, Literal, Error = Simple<char>> {
+use super::string::escape_parser;
+
+pub fn char_literal() -> impl Parser<char, Literal, Error = Simple<char>> + Clone
+{
     just('\'')
-        .ignore_then(none_of('\''))
+        .ignore_then(escape_parser().or(none_of("'\\\n")))
         .then_ignore(just('\''))
         .map(Literal::Char)
 }
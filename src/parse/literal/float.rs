@@ -1,10 +1,61 @@
-use crate::ast::Literal;
+use crate::ast::{Literal, NumberLiteral, NumberSuffix, Radix};
 use chumsky::prelude::*;
 
-pub fn float_literal() -> impl Parser<char, Literal, Error = Simple<char>> {
-    text::int(10)
-        .then(just('.').then(text::int(10)))
-        .map(|(int, (_, frac))| {
-            Literal::Float(format!("{}.{}", int, frac).parse().unwrap())
-        })
+use super::int::{digits, required_digits};
+
+pub fn float_literal(
+) -> impl Parser<char, Literal, Error = Simple<char>> + Clone {
+    let exponent = one_of("eE")
+        .chain(one_of("+-").or_not())
+        .chain::<char, _, _>(required_digits(10, "an exponent"))
+        .collect::<String>();
+
+    let fraction = digits(10)
+        .or_not()
+        .map(Option::unwrap_or_default)
+        .chain(just('.'))
+        .chain::<char, _, _>(digits(10))
+        .chain::<char, _, _>(
+            exponent.clone().or_not().map(Option::unwrap_or_default),
+        )
+        .collect::<String>();
+    let double =
+        fraction.or(digits(10).chain::<char, _, _>(exponent).collect());
+
+    let float = double
+        .clone()
+        .or(digits(10))
+        .then(one_of("fF"))
+        .map(|(digits, suffix)| (digits, Some(suffix)));
+
+    // The value is still produced when it is out of range, so that the
+    // literal isn't reparsed as an integer followed by an identifier.
+    float.or(double.map(|digits| (digits, None))).validate(
+        |(digits, suffix), span, emit| {
+            let value = digits.replace('_', "");
+            let text = match suffix {
+                Some(suffix) => format!("{}{}", digits, suffix),
+                None => digits,
+            };
+            // A value that doesn't parse lacks its exponent digits, which
+            // have already been reported.
+            let in_range = match suffix {
+                Some(_) => value.parse::<f32>().map_or(true, f32::is_finite),
+                None => value.parse::<f64>().map_or(true, f64::is_finite),
+            };
+            if !in_range {
+                emit(Simple::custom(
+                    span,
+                    "floating-point literal is out of range",
+                ));
+            }
+
+            Literal::Decimal(NumberLiteral {
+                value: value.parse().unwrap_or(f64::INFINITY),
+                text,
+                radix: Radix::Decimal,
+                suffix: suffix.map(|_| NumberSuffix::Float),
+            })
+        },
+    )
 }
@@ -1,6 +1,120 @@
-use crate::ast::Literal;
+use crate::ast::{Literal, NumberLiteral, NumberSuffix, Radix};
 use chumsky::prelude::*;
 
-pub fn int_literal() -> impl Parser<char, Literal, Error = Simple<char>> {
-    text::int(10).map(|s: String| Literal::Integer(s.parse().unwrap()))
+/// Parses digits of the given radix, allowing `_` separators between them.
+/// The separators are kept so the literal can be reproduced as written.
+pub fn digits(
+    radix: u32,
+) -> impl Parser<char, String, Error = Simple<char>> + Clone {
+    filter(move |c: &char| c.is_digit(radix))
+        .chain(
+            filter(move |c: &char| c.is_digit(radix) || *c == '_').repeated(),
+        )
+        .collect::<String>()
+        .try_map(|digits, span| {
+            if digits.ends_with('_') {
+                return Err(Simple::custom(
+                    span,
+                    "numeric literals cannot end with `_`",
+                ));
+            }
+            Ok(digits)
+        })
+}
+
+/// Parses the digits that have to follow a radix prefix or an exponent.
+/// Missing digits are reported rather than failing, since the prefix already
+/// commits the input to being a number.
+pub fn required_digits(
+    radix: u32,
+    what: &'static str,
+) -> impl Parser<char, String, Error = Simple<char>> + Clone {
+    digits(radix).or_not().validate(move |digits, span, emit| {
+        digits.unwrap_or_else(|| {
+            emit(Simple::custom(span, format!("expected digits in {}", what)));
+            String::new()
+        })
+    })
+}
+
+pub fn int_literal() -> impl Parser<char, Literal, Error = Simple<char>> + Clone
+{
+    let hex = just('0')
+        .chain(one_of("xX"))
+        .collect::<String>()
+        .then(required_digits(16, "a hexadecimal literal"))
+        .map(|parts| (parts, Radix::Hexadecimal));
+    let bin = just('0')
+        .chain(one_of("bB"))
+        .collect::<String>()
+        .then(required_digits(2, "a binary literal"))
+        .map(|parts| (parts, Radix::Binary));
+    let dec = digits(10)
+        .try_map(|digits, span| {
+            if digits.len() > 1 && digits.starts_with('0') {
+                return Err(Simple::custom(
+                    span,
+                    "decimal literals cannot have leading zeros",
+                ));
+            }
+            Ok((String::new(), digits))
+        })
+        .map(|parts| (parts, Radix::Decimal));
+
+    let suffix = choice((
+        one_of("uU")
+            .chain(just('L'))
+            .collect::<String>()
+            .map(|text| (text, NumberSuffix::UnsignedLong)),
+        one_of("uU").map(|c: char| (c.to_string(), NumberSuffix::Unsigned)),
+        just('L').map(|c| (c.to_string(), NumberSuffix::Long)),
+    ));
+
+    choice((hex, bin, dec)).then(suffix.or_not()).try_map(
+        |(((prefix, digits), radix), suffix), span| {
+            // Missing digits after a prefix have already been reported.
+            let value = match digits.replace('_', "") {
+                value if value.is_empty() => "0".to_string(),
+                value => value,
+            };
+            let base = match radix {
+                Radix::Binary => 2,
+                Radix::Decimal => 10,
+                Radix::Hexadecimal => 16,
+            };
+            let (suffix_text, suffix) = match suffix {
+                Some((text, kind)) => (text, Some(kind)),
+                None => (String::new(), None),
+            };
+            let text = format!("{}{}{}", prefix, digits, suffix_text);
+            let out_of_range = || {
+                Simple::custom(span.clone(), "integer literal is out of range")
+            };
+
+            match suffix {
+                Some(NumberSuffix::Unsigned | NumberSuffix::UnsignedLong) => {
+                    u64::from_str_radix(&value, base)
+                        .map(|value| {
+                            Literal::UnsignedInteger(NumberLiteral {
+                                value,
+                                text,
+                                radix,
+                                suffix,
+                            })
+                        })
+                        .map_err(|_| out_of_range())
+                }
+                _ => i64::from_str_radix(&value, base)
+                    .map(|value| {
+                        Literal::Integer(NumberLiteral {
+                            value,
+                            text,
+                            radix,
+                            suffix,
+                        })
+                    })
+                    .map_err(|_| out_of_range()),
+            }
+        },
+    )
 }
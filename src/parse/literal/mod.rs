@@ -1,11 +1,11 @@
-use crate::ast::{Expr, Literal};
+use crate::ast::{Expression, Literal};
 use boolean::boolean_literal;
 use char::char_literal;
 use chumsky::prelude::*;
 use float::float_literal;
 use int::int_literal;
 use null::null_literal;
-use string::string_literal;
+use string::string_parser;
 
 mod boolean;
 mod char;
@@ -14,46 +14,67 @@ mod int;
 mod null;
 mod string;
 
-#[allow(dead_code)]
-pub fn literal_parser() -> impl Parser<char, Literal, Error = Simple<char>> {
+pub fn literal_parser(
+) -> impl Parser<char, Literal, Error = Simple<char>> + Clone {
     choice((
         float_literal(),
         int_literal(),
-        string_literal(),
         char_literal(),
         boolean_literal(),
         null_literal(),
     ))
 }
 
-pub fn literal_expr_parser() -> impl Parser<char, Expr, Error = Simple<char>> {
-    choice((
-        float_literal(),
-        int_literal(),
-        string_literal(),
-        char_literal(),
-        boolean_literal(),
-        null_literal(),
-    ))
-    .map(Expr::Literal)
+pub fn literal_expr_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    string_parser(expr).or(literal_parser().map(Expression::Literal))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        ast::*,
+        parse::{expression::expr_parsers, statement::stmt_parser},
+    };
+    use chumsky::error::SimpleReason;
+
+    fn parse_string(source: &str) -> Result<Expression, Vec<Simple<char>>> {
+        literal_expr_parser(expr_parsers(stmt_parser()).0)
+            .then_ignore(end())
+            .parse(source)
+    }
+
+    fn number<T>(
+        value: T,
+        text: &str,
+        radix: Radix,
+        suffix: Option<NumberSuffix>,
+    ) -> NumberLiteral<T> {
+        NumberLiteral {
+            value,
+            text: text.to_string(),
+            radix,
+            suffix,
+        }
+    }
 
     #[test]
     fn parse_literal() {
-        assert_eq!(literal_parser().parse("123"), Ok(Literal::Integer(123)));
-
         assert_eq!(
-            literal_parser().parse("123.456"),
-            Ok(Literal::Float(123.456))
+            literal_parser().parse("123"),
+            Ok(Literal::Integer(number(123, "123", Radix::Decimal, None)))
         );
 
         assert_eq!(
-            literal_parser().parse("\"abc\""),
-            Ok(Literal::String("abc".to_string()))
+            literal_parser().parse("123.456"),
+            Ok(Literal::Decimal(number(
+                123.456,
+                "123.456",
+                Radix::Decimal,
+                None
+            )))
         );
 
         assert_eq!(literal_parser().parse("'a'"), Ok(Literal::Char('a')));
@@ -67,4 +88,141 @@ mod tests {
 
         assert_eq!(literal_parser().parse("null"), Ok(Literal::Null));
     }
+
+    #[test]
+    fn parse_numeric_literals() {
+        let parse = |source| literal_parser().then_ignore(end()).parse(source);
+        let integer = |source| match parse(source) {
+            Ok(Literal::Integer(NumberLiteral { value, .. })) => Some(value),
+            _ => None,
+        };
+        let decimal = |source| match parse(source) {
+            Ok(Literal::Decimal(NumberLiteral { value, .. })) => Some(value),
+            _ => None,
+        };
+
+        assert_eq!(integer("0xFF"), Some(255));
+        assert_eq!(integer("0b1010"), Some(10));
+        assert_eq!(integer("1_000_000"), Some(1_000_000));
+        assert_eq!(integer("10L"), Some(10));
+        assert_eq!(
+            parse("0xFFFF_FFFF_FFFF_FFFFUL"),
+            Ok(Literal::UnsignedInteger(number(
+                u64::MAX,
+                "0xFFFF_FFFF_FFFF_FFFFUL",
+                Radix::Hexadecimal,
+                Some(NumberSuffix::UnsignedLong)
+            )))
+        );
+        assert_eq!(decimal("1.5f"), Some(1.5));
+        assert_eq!(decimal("1e-9"), Some(1e-9));
+        assert_eq!(decimal(".5"), Some(0.5));
+        assert_eq!(decimal("2F"), Some(2.0));
+
+        assert!(parse("2.").is_err());
+        assert!(parse("1_").is_err());
+        assert!(parse("007").is_err());
+        let error = |source| {
+            parse(source)
+                .unwrap_err()
+                .into_iter()
+                .map(|error| match error.reason() {
+                    SimpleReason::Custom(message) => message.clone(),
+                    reason => format!("{:?}", reason),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            error("9223372036854775808"),
+            vec!["integer literal is out of range"]
+        );
+        assert_eq!(
+            error("1e39f"),
+            vec!["floating-point literal is out of range"]
+        );
+        assert_eq!(
+            error("1e999"),
+            vec!["floating-point literal is out of range"]
+        );
+        assert_eq!(
+            error("0x"),
+            vec!["expected digits in a hexadecimal literal"]
+        );
+        assert_eq!(error("0b"), vec!["expected digits in a binary literal"]);
+        assert_eq!(error("1e+"), vec!["expected digits in an exponent"]);
+    }
+
+    #[test]
+    fn preserve_number_spelling() {
+        let parse = |source| literal_parser().then_ignore(end()).parse(source);
+
+        assert_eq!(
+            parse("0B1010L"),
+            Ok(Literal::Integer(number(
+                10,
+                "0B1010L",
+                Radix::Binary,
+                Some(NumberSuffix::Long)
+            )))
+        );
+        assert_eq!(
+            parse("42u"),
+            Ok(Literal::UnsignedInteger(number(
+                42,
+                "42u",
+                Radix::Decimal,
+                Some(NumberSuffix::Unsigned)
+            )))
+        );
+        assert_eq!(
+            parse("1_0.5E+3f"),
+            Ok(Literal::Decimal(number(
+                10500.0,
+                "1_0.5E+3f",
+                Radix::Decimal,
+                Some(NumberSuffix::Float)
+            )))
+        );
+    }
+
+    #[test]
+    fn parse_strings() {
+        let string =
+            |s: &str| Ok(Expression::Literal(Literal::String(s.into())));
+
+        assert_eq!(parse_string("\"abc\""), string("abc"));
+        assert_eq!(
+            parse_string(r#""a\tb\"c\$d\u00e9$""#),
+            string("a\tb\"c$d\u{e9}$")
+        );
+        assert_eq!(
+            parse_string("\"\"\"a \"quoted\"\n\\n\"\"\"\""),
+            string("a \"quoted\"\n\\n\"")
+        );
+        assert_eq!(literal_parser().parse(r"'\''"), Ok(Literal::Char('\'')));
+        assert!(parse_string("\"a\nb\"").is_err());
+    }
+
+    #[test]
+    fn parse_string_templates() {
+        assert_eq!(
+            parse_string("\"Hello, $name! ${a}\""),
+            Ok(Expression::StringTemplate(vec![
+                StringTemplateExpression::Literal("Hello, ".to_string()),
+                StringTemplateExpression::Simple("name".to_string()),
+                StringTemplateExpression::Literal("! ".to_string()),
+                StringTemplateExpression::Block(Block {
+                    statements: vec![Statement::Expression(
+                        Expression::Reference(ReferenceExpression {
+                            parts: vec!["a".to_string()],
+                        })
+                    )],
+                }),
+            ]))
+        );
+        assert!(matches!(
+            parse_string("\"\"\"${x}\"\"\""),
+            Ok(Expression::StringTemplate(entries)) if entries.len() == 1
+        ));
+    }
 }
@@ -1,6 +1,7 @@
-use crate::ast::Literal;
+use crate::{ast::Literal, parse::ident::keyword};
 use chumsky::prelude::*;
 
-pub fn null_literal() -> impl Parser<char, Literal, Error = Simple<char>> {
-    just("null").to(Literal::Null)
+pub fn null_literal() -> impl Parser<char, Literal, Error = Simple<char>> + Clone
+{
+    keyword("null").to(Literal::Null)
 }
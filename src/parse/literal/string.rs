@@ -1,10 +1,107 @@
-use crate::ast::Literal;
+use crate::{ast::*, parse::ident::raw_ident_parser};
 use chumsky::prelude::*;
 
-pub fn string_literal() -> impl Parser<char, Literal, Error = Simple<char>> {
-    just('"')
-        .ignore_then(none_of('"').repeated())
-        .then_ignore(just('"'))
-        .collect::<String>()
-        .map(Literal::String)
+#[derive(Clone)]
+enum StringPart {
+    Char(char),
+    Template(StringTemplateExpression),
+}
+
+/// Parses a regular or raw string. Strings without templates are returned as
+/// `Literal::String`, the others as `Expression::StringTemplate`.
+pub fn string_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    let template = template_parser(expr).map(StringPart::Template);
+
+    let quote = just('"');
+    let raw_quote = quote
+        .then_ignore(just("\"\"\"").rewind())
+        .or(quote.then_ignore(just("\"\"").not().rewind()));
+    let raw = template
+        .clone()
+        .or(raw_quote.map(StringPart::Char))
+        .or(none_of('"').map(StringPart::Char))
+        .repeated()
+        .delimited_by(just("\"\"\""), just("\"\"\""));
+
+    let regular = template
+        .or(escape_parser().map(StringPart::Char))
+        .or(none_of("\"\\\n").map(StringPart::Char))
+        .repeated()
+        .delimited_by(quote, quote);
+
+    raw.or(regular).map(|parts| {
+        let mut entries = Vec::new();
+        let mut text = String::new();
+        for part in parts {
+            match part {
+                StringPart::Char(c) => text.push(c),
+                StringPart::Template(entry) => {
+                    if !text.is_empty() {
+                        entries.push(StringTemplateExpression::Literal(
+                            std::mem::take(&mut text),
+                        ));
+                    }
+                    entries.push(entry);
+                }
+            }
+        }
+
+        if entries.is_empty() {
+            return Expression::Literal(Literal::String(text));
+        }
+        if !text.is_empty() {
+            entries.push(StringTemplateExpression::Literal(text));
+        }
+        Expression::StringTemplate(entries)
+    })
+}
+
+/// Parses a `$name` or `${expr}` template entry. A `$` that is not followed
+/// by either is left to be parsed as a plain character.
+fn template_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, StringTemplateExpression, Error = Simple<char>> + Clone + 'a
+{
+    let block = expr.delimited_by(just('{'), just('}')).map(|expr| {
+        StringTemplateExpression::Block(Block {
+            statements: vec![Statement::Expression(expr)],
+        })
+    });
+
+    just('$').ignore_then(
+        block.or(raw_ident_parser().map(StringTemplateExpression::Simple)),
+    )
+}
+
+/// Parses an escape sequence such as `\n`, `\$` or `\u00e9`.
+pub fn escape_parser() -> impl Parser<char, char, Error = Simple<char>> + Clone
+{
+    let unicode = just('u').ignore_then(
+        filter(|c: &char| c.is_ascii_hexdigit())
+            .repeated()
+            .exactly(4)
+            .collect::<String>()
+            .try_map(|digits, span| {
+                u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| {
+                        Simple::custom(span, "invalid unicode escape")
+                    })
+            }),
+    );
+
+    just('\\').ignore_then(choice((
+        just('t').to('\t'),
+        just('b').to('\u{8}'),
+        just('n').to('\n'),
+        just('r').to('\r'),
+        just('\''),
+        just('"'),
+        just('\\'),
+        just('$'),
+        unicode,
+    )))
 }
@@ -1,4 +1,19 @@
+mod annotation;
 mod expression;
+pub mod file;
+mod ident;
+mod kdoc;
 mod literal;
 pub mod statement;
 mod ty;
+mod whitespace;
+
+use chumsky::prelude::*;
+
+/// Succeeds without consuming input unless the next character is one of
+/// `chars`. Used to keep operators from matching a prefix of a longer one.
+fn not_followed_by(
+    chars: &'static str,
+) -> impl Parser<char, (), Error = Simple<char>> + Clone {
+    one_of(chars).not().rewind().ignored().or(end())
+}
@@ -0,0 +1,69 @@
+use crate::{
+    ast::*,
+    parse::{
+        expression::call::call_args_parser, ident::keyword,
+        kdoc::doc_comment_parser, statement::block_parser, whitespace::Pad,
+    },
+};
+use chumsky::prelude::*;
+
+use super::{function::params_parser, modifier::modifiers_parser};
+
+pub fn primary_constructor_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, PrimaryConstructorDeclaration, Error = Simple<char>>
+       + Clone
+       + 'a {
+    modifiers_parser(expr.clone())
+        .then_ignore(keyword("constructor").pad())
+        .or_not()
+        .then(params_parser(expr))
+        .map(|(header, params)| {
+            let (annotations, modifiers) = header.unwrap_or_default();
+            PrimaryConstructorDeclaration {
+                annotations,
+                modifiers,
+                params,
+            }
+        })
+}
+
+pub fn constructor_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, ConstructorDeclaration, Error = Simple<char>> + Clone + 'a
+{
+    let delegate = just(':')
+        .pad()
+        .ignore_then(choice((
+            keyword("this").to(ConstructorDelegateKind::This),
+            keyword("super").to(ConstructorDelegateKind::Super),
+        )))
+        .then(call_args_parser(expr.clone()))
+        .map(|(kind, args)| ConstructorDelegate { kind, args });
+
+    doc_comment_parser()
+        .then(modifiers_parser(expr.clone()))
+        .then_ignore(keyword("constructor").pad())
+        .then(params_parser(expr))
+        .then(delegate.or_not())
+        .then(block_parser(stmt).or_not())
+        .map(
+            |((((doc, (annotations, modifiers)), params), delegate), body)| {
+                ConstructorDeclaration {
+                    doc,
+                    annotations,
+                    modifiers,
+                    params,
+                    delegate,
+                    body,
+                }
+            },
+        )
+}
+
+pub fn init_block_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Block, Error = Simple<char>> + Clone + 'a {
+    keyword("init").pad().ignore_then(block_parser(stmt))
+}
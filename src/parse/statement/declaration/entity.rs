@@ -0,0 +1,183 @@
+use crate::{
+    ast::*,
+    parse::{
+        expression::call::call_args_parser,
+        ident::{ident_parser, keyword},
+        kdoc::doc_comment_parser,
+        ty::{type_constraints_parser, type_params_parser, type_parser},
+        whitespace::Pad,
+    },
+};
+use chumsky::prelude::*;
+
+use super::{
+    constructor::primary_constructor_parser, modifier::modifiers_parser,
+};
+
+pub fn entity_parser<'a>(
+    member: impl Parser<char, Declaration, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<
+    char,
+    (Vec<AnnotationSet>, EntityDeclaration),
+    Error = Simple<char>,
+> + Clone
+       + 'a {
+    let kind = choice((
+        keyword("class").to(EntityDeclarationKind::Class),
+        keyword("interface").to(EntityDeclarationKind::Interface),
+        keyword("object").to(EntityDeclarationKind::Object),
+    ))
+    .pad();
+
+    let members = member.then_ignore(just(';').pad().repeated()).repeated();
+    let class_body = members
+        .clone()
+        .delimited_by(just('{').pad(), just('}').pad());
+
+    let enum_entry = doc_comment_parser()
+        .then(modifiers_parser(nested.clone()))
+        .then(ident_parser().pad())
+        .then(call_args_parser(nested.clone()).or_not())
+        .then(class_body.clone().or_not())
+        .map(|((((doc, (annotations, modifiers)), name), args), inner)| {
+            Declaration {
+                doc,
+                annotations,
+                kind: DeclarationKind::EnumEntry(EnumEntryDeclaration {
+                    modifiers,
+                    name,
+                    args: args.unwrap_or_default(),
+                    inner: inner.unwrap_or_default(),
+                }),
+            }
+        });
+    let enum_body = just('{')
+        .pad()
+        .ignore_then(enum_entry.separated_by(just(',').pad()).allow_trailing())
+        .then(just(';').pad().ignore_then(members).or_not())
+        .then_ignore(just('}').pad())
+        .map(|(entries, members)| {
+            entries
+                .into_iter()
+                .chain(members.into_iter().flatten())
+                .collect()
+        });
+
+    let body = class_body
+        .or(enum_body)
+        .or_not()
+        .map(Option::unwrap_or_default);
+
+    modifiers_parser(nested.clone())
+        .then(kind)
+        .then(ident_parser().pad().or_not())
+        .then(type_params_parser(nested.clone()).or_not())
+        .then(primary_constructor_parser(nested.clone()).or_not())
+        .then(super_types_parser(expr, nested.clone()).or_not())
+        .then(type_constraints_parser(nested).or_not())
+        .then(body)
+        .try_map(
+            |(
+                (
+                    (
+                        (
+                            (
+                                (((annotations, modifiers), kind), name),
+                                type_params,
+                            ),
+                            primary_constructor,
+                        ),
+                        extends,
+                    ),
+                    bounds,
+                ),
+                members,
+            ),
+             span| {
+                let has = |modifier| modifiers.contains(&modifier);
+                let kind = match kind {
+                    EntityDeclarationKind::Class if has(Modifier::Enum) => {
+                        EntityDeclarationKind::Enum
+                    }
+                    EntityDeclarationKind::Interface if has(Modifier::Fun) => {
+                        EntityDeclarationKind::FunInterface
+                    }
+                    EntityDeclarationKind::Object
+                        if has(Modifier::Companion) =>
+                    {
+                        EntityDeclarationKind::CompanionObject
+                    }
+                    EntityDeclarationKind::Object if has(Modifier::Data) => {
+                        EntityDeclarationKind::DataObject
+                    }
+                    kind => kind,
+                };
+
+                if name.is_none()
+                    && kind != EntityDeclarationKind::CompanionObject
+                {
+                    return Err(Simple::custom(
+                        span,
+                        "expected a declaration name",
+                    ));
+                }
+
+                let (constructors, inner): (Vec<_>, Vec<_>) =
+                    members.into_iter().partition(|decl| {
+                        matches!(decl.kind, DeclarationKind::Constructor(_))
+                    });
+                let constructors = constructors
+                    .into_iter()
+                    .filter_map(|decl| match decl.kind {
+                        DeclarationKind::Constructor(constructor) => {
+                            Some(constructor)
+                        }
+                        _ => None,
+                    })
+                    .collect();
+
+                let has_entries = inner.iter().any(|decl| {
+                    matches!(decl.kind, DeclarationKind::EnumEntry(_))
+                });
+                if has_entries && kind != EntityDeclarationKind::Enum {
+                    return Err(Simple::custom(
+                        span,
+                        "enum entries are only allowed in enum classes",
+                    ));
+                }
+
+                Ok((
+                    annotations,
+                    EntityDeclaration {
+                        modifiers,
+                        kind,
+                        name,
+                        type_params: type_params.unwrap_or_default(),
+                        primary_constructor,
+                        constructors,
+                        extends: extends.unwrap_or_default(),
+                        bounds: bounds.unwrap_or_default(),
+                        inner,
+                    },
+                ))
+            },
+        )
+}
+
+/// Parses the `: Base(args), Interface by delegate` list that follows a
+/// class or object header.
+pub fn super_types_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Vec<SuperType>, Error = Simple<char>> + Clone + 'a {
+    let super_type = type_parser(nested.clone())
+        .then(call_args_parser(nested).or_not())
+        .then(keyword("by").pad().ignore_then(expr).or_not())
+        .map(|((ty, args), delegate)| SuperType { ty, args, delegate });
+
+    just(':')
+        .pad()
+        .ignore_then(super_type.separated_by(just(',').pad()).at_least(1))
+}
@@ -1,58 +1,188 @@
-use crate::ast::*;
-use crate::parse::statement::inheritance_parser;
-use crate::parse::statement::visibility_parser;
-use crate::parse::ty::type_parser;
+use crate::{
+    ast::*,
+    parse::{
+        ident::{ident_parser, keyword},
+        statement::block_parser,
+        ty::{type_constraints_parser, type_params_parser, type_parser},
+        whitespace::Pad,
+    },
+};
 use chumsky::prelude::*;
-use chumsky::text;
-
-pub fn function_parser(
-    stmt_parser: impl Parser<char, Stmt, Error = Simple<char>> + Clone,
-) -> impl Parser<char, Stmt, Error = Simple<char>> {
-    let name = text::ident().padded();
-    let params = text::ident()
-        .padded()
-        .then(just(':').padded().ignore_then(type_parser()))
-        .padded()
-        .separated_by(just(',').padded())
-        .delimited_by(just('('), just(')'))
-        .padded();
-    let ty = just(':')
-        .padded()
-        .ignore_then(type_parser())
-        .or_not()
-        .padded();
-    let body = choice((
-        just("=")
-            .padded()
-            .ignore_then(stmt_parser.clone())
-            .map(|stmt| Block {
-                statements: vec![Box::new(stmt)],
-            }),
-        just("{")
-            .padded()
-            .ignore_then(stmt_parser.map(Box::new).repeated())
-            .then_ignore(just("}").padded())
-            .map(|stmts| Block { statements: stmts }),
-    ));
-
-    visibility_parser()
-        .or_not()
-        .then(inheritance_parser().or_not())
-        .then_ignore(just("fun").padded())
-        .then(name)
-        .then(params)
-        .then(ty)
+
+use super::modifier::modifiers_parser;
+
+pub fn function_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<
+    char,
+    (Vec<AnnotationSet>, FunctionDeclaration),
+    Error = Simple<char>,
+> + Clone
+       + 'a {
+    let return_ty = just(':')
+        .pad()
+        .ignore_then(type_parser(nested.clone()))
+        .or_not();
+    let body = function_body_parser(stmt, expr).or_not();
+
+    modifiers_parser(nested.clone())
+        .then_ignore(keyword("fun").pad())
+        .then(type_params_parser(nested.clone()).or_not())
+        .then(receiver_name_parser(nested.clone()))
+        .then(params_parser(nested.clone()))
+        .then(return_ty)
+        .then(type_constraints_parser(nested).or_not())
         .then(body)
         .map(
-            |(((((visibility, inheritance), name), params), ty), body)| {
-                Stmt::Declaration(DeclarationKind::Function {
-                    visibility,
-                    inheritance,
+            |(
+                (
+                    (
+                        (
+                            (
+                                ((annotations, modifiers), type_params),
+                                (receiver, name),
+                            ),
+                            params,
+                        ),
+                        return_ty,
+                    ),
+                    bounds,
+                ),
+                body,
+            )| {
+                (
+                    annotations,
+                    FunctionDeclaration {
+                        modifiers,
+                        type_params: type_params.unwrap_or_default(),
+                        receiver,
+                        name: Some(name),
+                        params,
+                        return_ty,
+                        bounds: bounds.unwrap_or_default(),
+                        body,
+                    },
+                )
+            },
+        )
+}
+
+/// Parses the name of a function or property, preceded by the receiver type
+/// of an extension as in `List<T>.second`.
+///
+/// The type parser reads `String.foo` as a qualified type, so the last
+/// segment of such a type is split off as the name.
+pub fn receiver_name_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, (Option<Type>, String), Error = Simple<char>> + Clone + 'a
+{
+    let extension = type_parser(expr)
+        .then(just('.').ignore_then(ident_parser()).pad().or_not())
+        .try_map(|(ty, name), span| match (ty, name) {
+            (receiver, Some(name)) => Ok((Some(receiver), name)),
+            (Type::Simple(ty), None)
+                if ty.type_args.is_empty() && !ty.is_nullable =>
+            {
+                let SimpleType {
+                    qualifier, name, ..
+                } = *ty;
+                let receiver = qualifier.map(Type::Simple);
+                name.map(|name| (receiver, name))
+                    .ok_or_else(|| Simple::custom(span, "expected a name"))
+            }
+            _ => Err(Simple::custom(span, "expected a name")),
+        });
+
+    // `fun suspend()` would otherwise be read as the start of a
+    // `suspend` function type.
+    extension.or(ident_parser().pad().map(|name| (None, name)))
+}
+
+/// Parses an anonymous function such as `fun(x: Int): Int { return x }`.
+pub fn anonymous_function_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, FunctionDeclaration, Error = Simple<char>> + Clone + 'a {
+    let return_ty = just(':')
+        .pad()
+        .ignore_then(type_parser(expr.clone()))
+        .or_not();
+    let body = function_body_parser(stmt, expr.clone()).or_not();
+
+    keyword("fun")
+        .pad()
+        .ignore_then(params_parser(expr.clone()))
+        .then(return_ty)
+        .then(type_constraints_parser(expr).or_not())
+        .then(body)
+        .map(
+            |(((params, return_ty), bounds), body)| FunctionDeclaration {
+                modifiers: Vec::new(),
+                type_params: Vec::new(),
+                receiver: None,
+                name: None,
+                params,
+                return_ty,
+                bounds: bounds.unwrap_or_default(),
+                body,
+            },
+        )
+}
+
+/// Parses either a `{ ... }` block or an `= expression` body, the latter
+/// being stored as a block with a single expression statement.
+pub fn function_body_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Block, Error = Simple<char>> + Clone + 'a {
+    choice((
+        just('=').pad().ignore_then(expr).map(|expr| Block {
+            statements: vec![Statement::Expression(expr)],
+        }),
+        block_parser(stmt),
+    ))
+}
+
+pub fn params_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Vec<Param>, Error = Simple<char>> + Clone + 'a {
+    param_parser(expr)
+        .separated_by(just(',').pad())
+        .allow_trailing()
+        .delimited_by(just('('), just(')').pad())
+        .pad()
+}
+
+/// Parses a value parameter. Parameters declared with `val` or `var` are
+/// only meaningful in primary constructors, where they declare properties.
+pub fn param_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Param, Error = Simple<char>> + Clone + 'a {
+    let property = keyword("val")
+        .to(false)
+        .or(keyword("var").to(true))
+        .pad()
+        .or_not();
+
+    modifiers_parser(expr.clone())
+        .then(property)
+        .then(ident_parser().pad())
+        .then_ignore(just(':').pad())
+        .then(type_parser(expr.clone()))
+        .then(just('=').pad().ignore_then(expr).or_not())
+        .map(
+            |(((((annotations, modifiers), property), name), ty), default)| {
+                Param {
+                    annotations,
+                    modifiers,
+                    is_property: property.is_some(),
+                    is_mutable: property.unwrap_or(false),
                     name,
-                    params,
                     ty,
-                    body,
-                })
+                    default,
+                }
             },
         )
 }
@@ -1,21 +1,819 @@
+use crate::{ast::*, parse::kdoc::doc_comment_parser};
 use chumsky::prelude::*;
-use conditional::conditional_parser;
+use constructor::{constructor_parser, init_block_parser};
+use entity::entity_parser;
 use function::function_parser;
 use property::property_parser;
-use repeat::repeat_parser;
+use type_alias::type_alias_parser;
 
-use crate::ast::Stmt;
-
-pub mod conditional;
+mod constructor;
+mod entity;
 mod function;
+mod modifier;
 mod property;
-pub mod repeat;
-
-pub fn declaration_parser(
-    stmt_parser: impl Parser<char, Stmt, Error = Simple<char>> + Clone,
-) -> impl Parser<char, Stmt, Error = Simple<char>> {
-    property_parser()
-        .or(function_parser(stmt_parser.clone()))
-        .or(repeat_parser(stmt_parser.clone()))
-        .or(conditional_parser(stmt_parser))
+mod type_alias;
+
+pub use entity::super_types_parser;
+pub use function::{anonymous_function_parser, param_parser};
+pub use modifier::modifiers_parser;
+pub use property::{accessor_start_parser, tuple_parser, var_parser};
+
+pub fn declaration_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    init: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Declaration, Error = Simple<char>> + Clone + 'a {
+    recursive(|decl| {
+        let member = member_parser(stmt.clone(), nested.clone(), decl);
+
+        doc_comment_parser()
+            .then(choice((
+                entity_parser(member, expr.clone(), nested.clone()).map(
+                    |(annotations, entity)| {
+                        (annotations, DeclarationKind::Entity(entity))
+                    },
+                ),
+                function_parser(stmt.clone(), expr.clone(), nested.clone())
+                    .map(|(annotations, function)| {
+                        (annotations, DeclarationKind::Function(function))
+                    }),
+                property_parser(stmt, expr, nested.clone(), init, true).map(
+                    |(annotations, property)| {
+                        (annotations, DeclarationKind::Property(property))
+                    },
+                ),
+                type_alias_parser(nested).map(|(annotations, alias)| {
+                    (annotations, DeclarationKind::TypeAlias(alias))
+                }),
+            )))
+            .map(|(doc, (annotations, kind))| Declaration {
+                doc,
+                annotations,
+                kind,
+            })
+            .boxed()
+    })
+}
+
+/// Parses a declaration in a class body, where constructors and init blocks
+/// are allowed in addition to regular declarations.
+pub fn member_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    decl: impl Parser<char, Declaration, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Declaration, Error = Simple<char>> + Clone + 'a {
+    // The doc comment and annotations of a constructor are kept on the
+    // constructor itself, which is moved out of the class members.
+    let constructor =
+        constructor_parser(stmt.clone(), expr).map(|constructor| Declaration {
+            doc: None,
+            annotations: Vec::new(),
+            kind: DeclarationKind::Constructor(constructor),
+        });
+    let init_block = doc_comment_parser().then(init_block_parser(stmt)).map(
+        |(doc, block)| Declaration {
+            doc,
+            annotations: Vec::new(),
+            kind: DeclarationKind::InitBlock(block),
+        },
+    );
+
+    constructor.or(init_block).or(decl)
+}
+
+/// Parses a declaration in statement position, where properties cannot
+/// declare accessors.
+pub fn local_declaration_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    init: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Declaration, Error = Simple<char>> + Clone + 'a {
+    doc_comment_parser()
+        .then(property_parser(
+            stmt.clone(),
+            expr.clone(),
+            nested.clone(),
+            expr.clone(),
+            false,
+        ))
+        .map(|(doc, (annotations, property))| Declaration {
+            doc,
+            annotations,
+            kind: DeclarationKind::Property(property),
+        })
+        .or(declaration_parser(stmt, expr, nested, init))
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{expression::expr_parsers, statement::stmt_parser};
+
+    fn parse(source: &str) -> Result<Declaration, Vec<Simple<char>>> {
+        let stmt = stmt_parser();
+        let (expr, nested, init) = expr_parsers(stmt.clone());
+        declaration_parser(stmt, expr, nested, init)
+            .then_ignore(end())
+            .parse(source)
+    }
+
+    fn parse_entity(source: &str) -> EntityDeclaration {
+        match parse(source).unwrap().kind {
+            DeclarationKind::Entity(entity) => entity,
+            kind => panic!("expected an entity, found {:?}", kind),
+        }
+    }
+
+    fn simple_type(name: &str, type_args: Vec<Type>) -> Type {
+        Type::Simple(Box::new(SimpleType {
+            qualifier: None,
+            name: Some(name.to_string()),
+            type_args: type_args
+                .into_iter()
+                .map(|ty| TypeBound {
+                    annotations: Vec::new(),
+                    ty,
+                    kind: BoundKind::Unconstrained,
+                })
+                .collect(),
+            is_nullable: false,
+        }))
+    }
+
+    #[test]
+    fn parse_class() {
+        let entity = parse_entity(
+            "data class Point<out T : Number>(x: T, y: T) : Comparable<T>, \
+             Base(1) where T : Any { fun norm(): T = x }",
+        );
+
+        assert_eq!(entity.modifiers, vec![Modifier::Data]);
+        assert_eq!(entity.kind, EntityDeclarationKind::Class);
+        assert_eq!(entity.name.as_deref(), Some("Point"));
+        assert_eq!(
+            entity.type_params,
+            vec![TypeParam {
+                annotations: vec![],
+                modifiers: vec![Modifier::Out],
+                name: "T".to_string(),
+                bounds: vec![TypeBound {
+                    annotations: Vec::new(),
+                    ty: simple_type("Number", vec![]),
+                    kind: BoundKind::Unconstrained,
+                }],
+            }]
+        );
+        assert_eq!(
+            entity.primary_constructor.map(|ctor| ctor.params.len()),
+            Some(2)
+        );
+        assert_eq!(
+            entity.extends,
+            vec![
+                SuperType {
+                    ty: simple_type(
+                        "Comparable",
+                        vec![simple_type("T", vec![])]
+                    ),
+                    args: None,
+                    delegate: None,
+                },
+                SuperType {
+                    ty: simple_type("Base", vec![]),
+                    args: Some(vec![CallArg {
+                        name: None,
+                        value: Box::new(Expression::Literal(Literal::Integer(
+                            NumberLiteral {
+                                value: 1,
+                                text: "1".to_string(),
+                                radix: Radix::Decimal,
+                                suffix: None,
+                            }
+                        ))),
+                        is_spread: false,
+                    }]),
+                    delegate: None,
+                },
+            ]
+        );
+        assert_eq!(
+            entity.bounds,
+            vec![TypeConstraint {
+                annotations: vec![],
+                name: "T".to_string(),
+                ty: simple_type("Any", vec![]),
+            }]
+        );
+        assert!(matches!(
+            entity.inner.as_slice(),
+            [Declaration {
+                kind: DeclarationKind::Function(_),
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn parse_entity_kinds() {
+        let kind = |source| parse_entity(source).kind;
+
+        assert_eq!(kind("interface Shape"), EntityDeclarationKind::Interface);
+        assert_eq!(kind("object Registry {}"), EntityDeclarationKind::Object);
+        assert_eq!(kind("enum class Color"), EntityDeclarationKind::Enum);
+
+        let companion = parse_entity("companion object {}");
+        assert_eq!(companion.kind, EntityDeclarationKind::CompanionObject);
+        assert_eq!(companion.name, None);
+        assert_eq!(
+            parse_entity("companion object Companion").name.as_deref(),
+            Some("Companion")
+        );
+
+        assert!(parse("object {}").is_err());
+    }
+
+    #[test]
+    fn parse_modifiers() {
+        let data_object = parse_entity("private data object Empty : List");
+        assert_eq!(data_object.kind, EntityDeclarationKind::DataObject);
+        assert_eq!(
+            data_object.modifiers,
+            vec![Modifier::Private, Modifier::Data]
+        );
+
+        let fun_interface = parse_entity("fun interface Action { fun run() }");
+        assert_eq!(fun_interface.kind, EntityDeclarationKind::FunInterface);
+        assert_eq!(fun_interface.modifiers, vec![Modifier::Fun]);
+
+        assert_eq!(
+            parse_entity("public companion object").kind,
+            EntityDeclarationKind::CompanionObject
+        );
+        assert_eq!(
+            parse_entity("annotation class Marker").modifiers,
+            vec![Modifier::Annotation]
+        );
+
+        let value_class =
+            parse("@JvmInline value class Id(val raw: Int)").unwrap();
+        assert_eq!(value_class.annotations.len(), 1);
+        assert!(matches!(
+            value_class.kind,
+            DeclarationKind::Entity(EntityDeclaration { modifiers, .. })
+                if modifiers == vec![Modifier::Value]
+        ));
+
+        let function = parse(
+            "override @Deprecated(\"x\") public suspend inline \
+             fun f(vararg data: Int, value: Int) {}",
+        )
+        .unwrap();
+        assert_eq!(function.annotations.len(), 1);
+        let DeclarationKind::Function(function) = function.kind else {
+            panic!("expected a function");
+        };
+        assert_eq!(
+            function.modifiers,
+            vec![
+                Modifier::Override,
+                Modifier::Public,
+                Modifier::Suspend,
+                Modifier::Inline,
+            ]
+        );
+        assert_eq!(function.params[0].modifiers, vec![Modifier::Vararg]);
+        assert_eq!(function.params[0].name, "data");
+        assert!(function.params[1].modifiers.is_empty());
+        assert_eq!(function.params[1].name, "value");
+
+        assert!(matches!(
+            parse("context(Logger, Scope) fun log() {}").unwrap().kind,
+            DeclarationKind::Function(FunctionDeclaration { modifiers, .. })
+                if modifiers == vec![Modifier::Context(vec![
+                    simple_type("Logger", vec![]),
+                    simple_type("Scope", vec![]),
+                ])]
+        ));
+        assert_eq!(
+            parse_property("private lateinit var x: Foo").modifiers,
+            vec![Modifier::Private, Modifier::Lateinit]
+        );
+        assert_eq!(
+            parse_property("const private val X = 1").modifiers,
+            vec![Modifier::Const, Modifier::Private]
+        );
+    }
+
+    #[test]
+    fn parse_soft_keyword_names() {
+        assert!(matches!(
+            parse("fun `returns empty list`() {}").unwrap().kind,
+            DeclarationKind::Function(FunctionDeclaration { name: Some(name), .. })
+                if name == "returns empty list"
+        ));
+        assert_eq!(
+            parse_property("val value = data").vars.vars[0].name,
+            "value"
+        );
+
+        let entity = parse_entity(
+            "class Box(val data: Int, open: Boolean) {
+                val field get() = data
+                fun set(value: Int) {}
+            }",
+        );
+        assert_eq!(
+            entity
+                .primary_constructor
+                .unwrap()
+                .params
+                .iter()
+                .map(|param| param.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["data", "open"]
+        );
+        assert_eq!(entity.inner.len(), 2);
+
+        assert!(parse("val class = 1").is_err());
+    }
+
+    #[test]
+    fn collect_doc_comments() {
+        let summary = |source| {
+            parse(source).unwrap().doc.and_then(|doc| doc.summary).map(
+                |paragraph| match paragraph.spans.as_slice() {
+                    [KDocSpan::Text(text)] => text.clone(),
+                    spans => panic!("expected plain text, found {:?}", spans),
+                },
+            )
+        };
+
+        assert_eq!(
+            summary(
+                "/** Adds two numbers. */\nfun add(a: Int, b: Int) = a + b"
+            ),
+            Some("Adds two numbers.".to_string())
+        );
+        assert_eq!(
+            summary("/** Old. */\n// Unrelated.\n/** New. */\n@Ann val x = 1"),
+            Some("New.".to_string())
+        );
+        assert_eq!(parse("/**/ class A").unwrap().doc, None);
+        assert_eq!(
+            parse("/* Not a doc comment. */ class A").unwrap().doc,
+            None
+        );
+
+        let entity = parse_entity(
+            "class A {
+                /**
+                 * The value, see [B].
+                 */
+                val x = 1
+
+                fun y() {}
+            }",
+        );
+        assert_eq!(
+            entity.inner[0].doc,
+            Some(Box::new(KDoc {
+                summary: Some(KDocParagraph {
+                    spans: vec![
+                        KDocSpan::Text("The value, see ".to_string()),
+                        KDocSpan::Link {
+                            label: None,
+                            target: vec!["B".to_string()],
+                        },
+                        KDocSpan::Text(".".to_string()),
+                    ],
+                }),
+                description: vec![],
+                tags: vec![],
+            }))
+        );
+        assert_eq!(entity.inner[1].doc, None);
+    }
+
+    #[test]
+    fn parse_nested_classes() {
+        let entity = parse_entity(
+            "sealed class Outer private constructor() {
+                inner class Inner
+                abstract class Nested : Outer()
+                companion object Factory
+            }",
+        );
+
+        assert_eq!(entity.modifiers, vec![Modifier::Sealed]);
+        assert_eq!(
+            entity.primary_constructor,
+            Some(PrimaryConstructorDeclaration {
+                annotations: Vec::new(),
+                modifiers: vec![Modifier::Private],
+                params: vec![],
+            })
+        );
+
+        let names = entity
+            .inner
+            .iter()
+            .map(|decl| match &decl.kind {
+                DeclarationKind::Entity(entity) => entity.name.as_deref(),
+                _ => panic!("expected an entity"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![Some("Inner"), Some("Nested"), Some("Factory")]);
+    }
+
+    #[test]
+    fn parse_enum_entries() {
+        let entity = parse_entity(
+            "enum class Color(rgb: Int) {
+                RED(16711680) { override fun x() = 1 },
+                GREEN(65280),
+                BLUE,
+                ;
+
+                fun x() = 0
+            }",
+        );
+
+        assert_eq!(entity.kind, EntityDeclarationKind::Enum);
+
+        let entries = entity
+            .inner
+            .iter()
+            .filter_map(|decl| match &decl.kind {
+                DeclarationKind::EnumEntry(entry) => Some(entry),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| entry.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["RED", "GREEN", "BLUE"]
+        );
+        assert_eq!(entries[0].args.len(), 1);
+        assert_eq!(entries[0].inner.len(), 1);
+        assert!(entries[2].args.is_empty());
+        assert!(matches!(
+            entity.inner.last().map(|decl| &decl.kind),
+            Some(DeclarationKind::Function(_))
+        ));
+
+        assert_eq!(parse_entity("enum class E { A, B, }").inner.len(), 2);
+        assert_eq!(parse_entity("enum class E { ; fun x() }").inner.len(), 1);
+        assert!(parse("class A { RED, GREEN }").is_err());
+    }
+
+    #[test]
+    fn parse_constructors() {
+        let entity = parse_entity(
+            r#"class A private constructor(val x: Int, var y: String = "") {
+                init { println(x) }
+
+                constructor(x: Int) : this(x, "") {
+                    println(y)
+                }
+
+                constructor() : super()
+            }"#,
+        );
+
+        let primary = entity.primary_constructor.unwrap();
+        assert_eq!(primary.modifiers, vec![Modifier::Private]);
+        assert_eq!(
+            primary.params[1],
+            Param {
+                annotations: vec![],
+                modifiers: vec![],
+                is_property: true,
+                is_mutable: true,
+                name: "y".to_string(),
+                ty: simple_type("String", vec![]),
+                default: Some(Expression::Literal(Literal::String(
+                    String::new()
+                ))),
+            }
+        );
+        assert!(primary.params[0].is_property);
+        assert!(!primary.params[0].is_mutable);
+
+        assert_eq!(entity.constructors.len(), 2);
+        let delegate = entity.constructors[0].delegate.as_ref().unwrap();
+        assert_eq!(delegate.kind, ConstructorDelegateKind::This);
+        assert_eq!(delegate.args.len(), 2);
+        assert!(entity.constructors[0].body.is_some());
+        assert_eq!(
+            entity.constructors[1].delegate,
+            Some(ConstructorDelegate {
+                kind: ConstructorDelegateKind::Super,
+                args: vec![],
+            })
+        );
+        assert_eq!(entity.constructors[1].body, None);
+
+        assert!(matches!(
+            entity.inner.as_slice(),
+            [Declaration {
+                kind: DeclarationKind::InitBlock(Block { statements }),
+                ..
+            }] if statements.len() == 1
+        ));
+
+        let entity = parse_entity(
+            "class B {
+                /** Injected. */
+                @Inject constructor(x: Int) : this()
+            }",
+        );
+        let [ConstructorDeclaration {
+            doc: Some(doc),
+            annotations,
+            ..
+        }] = entity.constructors.as_slice()
+        else {
+            panic!("expected a documented constructor");
+        };
+        assert!(doc.summary.is_some());
+        assert_eq!(annotations.len(), 1);
+    }
+
+    #[test]
+    fn parse_multiline_arguments() {
+        let is_sum = |expr: &Expression| {
+            matches!(
+                expr,
+                Expression::BinaryOp(BinaryOperation {
+                    op: BinaryOperator::Operator(BinaryOp::Add),
+                    ..
+                })
+            )
+        };
+
+        let declaration = parse("@Ann(a\n + b) fun f() {}").unwrap();
+        assert!(is_sum(
+            &declaration.annotations[0].annotations[0].args[0].value
+        ));
+
+        let entity = parse_entity("enum class E(val x: Int) { A(1\n + 2) }");
+        let DeclarationKind::EnumEntry(entry) = &entity.inner[0].kind else {
+            panic!("expected an enum entry");
+        };
+        assert!(is_sum(&entry.args[0].value));
+
+        let entity = parse_entity("class A : Base(a\n + b)");
+        let args = entity.extends[0].args.as_ref().unwrap();
+        assert!(is_sum(&args[0].value));
+
+        let entity =
+            parse_entity("class A { constructor(x: Int) : this(x\n + 1) }");
+        let delegate = entity.constructors[0].delegate.as_ref().unwrap();
+        assert!(is_sum(&delegate.args[0].value));
+
+        let function = parse_function("fun f(x: Int = a\n + b) {}");
+        assert!(is_sum(function.params[0].default.as_ref().unwrap()));
+    }
+
+    fn parse_function(source: &str) -> FunctionDeclaration {
+        match parse(source).unwrap().kind {
+            DeclarationKind::Function(function) => function,
+            kind => panic!("expected a function, found {:?}", kind),
+        }
+    }
+
+    #[test]
+    fn parse_extensions() {
+        let function = parse_function("fun String.foo() {}");
+        assert_eq!(function.receiver, Some(simple_type("String", vec![])));
+        assert_eq!(function.name.as_deref(), Some("foo"));
+
+        let function = parse_function("fun <T> List<T>.second(): T = this[1]");
+        assert_eq!(function.type_params.len(), 1);
+        assert_eq!(
+            function.receiver,
+            Some(simple_type("List", vec![simple_type("T", vec![])]))
+        );
+        assert_eq!(function.name.as_deref(), Some("second"));
+
+        let function = parse_function("fun String?.orEmpty() = this ?: \"\"");
+        assert!(matches!(
+            function.receiver,
+            Some(Type::Simple(ty)) if ty.is_nullable
+        ));
+
+        let function =
+            parse_function("fun <T> T.check() where T : Comparable<T> {}");
+        assert_eq!(function.bounds.len(), 1);
+        assert_eq!(parse_function("fun foo() {}").receiver, None);
+        assert_eq!(
+            parse_function("fun suspend() {}").name.as_deref(),
+            Some("suspend")
+        );
+
+        let property = parse_property(
+            "val String.lastChar: Char get() = this[length - 1]",
+        );
+        assert_eq!(property.receiver, Some(simple_type("String", vec![])));
+        assert_eq!(property.vars.vars[0].name, "lastChar");
+        assert_eq!(property.vars.vars[0].ty, Some(simple_type("Char", vec![])));
+        assert_eq!(property.accessors.len(), 1);
+
+        let property = parse_property(
+            "val <T> List<T>.penultimate: T where T : Any
+                get() = this[size - 2]",
+        );
+        assert_eq!(property.type_params.len(), 1);
+        assert_eq!(
+            property.receiver,
+            Some(simple_type("List", vec![simple_type("T", vec![])]))
+        );
+        assert_eq!(property.vars.vars[0].name, "penultimate");
+        assert_eq!(property.bounds.len(), 1);
+        assert_eq!(property.accessors.len(), 1);
+    }
+
+    fn parse_property(source: &str) -> PropertyDeclaration {
+        match parse(source).unwrap().kind {
+            DeclarationKind::Property(property) => property,
+            kind => panic!("expected a property, found {:?}", kind),
+        }
+    }
+
+    #[test]
+    fn parse_property_accessors() {
+        let field = || {
+            Box::new(Expression::Reference(ReferenceExpression {
+                parts: vec!["field".to_string()],
+            }))
+        };
+
+        assert_eq!(
+            parse_property("val x: Int get() = field").accessors,
+            vec![PropertyAccessor::Getter {
+                annotations: vec![],
+                modifiers: vec![],
+                return_ty: None,
+                body: Some(Block {
+                    statements: vec![Statement::Expression(*field())],
+                }),
+            }]
+        );
+
+        let setter = PropertyAccessor::Setter {
+            annotations: vec![],
+            modifiers: vec![Modifier::Private],
+            field: Some(PropertySetterField {
+                name: "value".to_string(),
+                ty: None,
+            }),
+            return_ty: None,
+            body: Some(Block {
+                statements: vec![Statement::Expression(Expression::BinaryOp(
+                    BinaryOperation {
+                        lhs: field(),
+                        op: BinaryOperator::Operator(BinaryOp::Assign),
+                        rhs: Box::new(Expression::Reference(
+                            ReferenceExpression {
+                                parts: vec!["value".to_string()],
+                            },
+                        )),
+                    },
+                ))],
+            }),
+        };
+        for source in [
+            "var y = 0 private set(value) { field = value }",
+            "var y = 0
+                private set(value) { field = value }",
+        ] {
+            let property = parse_property(source);
+            assert!(matches!(
+                property.init,
+                Some(Expression::Literal(Literal::Integer(_)))
+            ));
+            assert_eq!(property.accessors, vec![setter.clone()]);
+        }
+
+        let property = parse_property("var y = 0 private set");
+        assert!(matches!(
+            property.init,
+            Some(Expression::Literal(Literal::Integer(_)))
+        ));
+        assert!(matches!(
+            property.accessors.as_slice(),
+            [PropertyAccessor::Setter { modifiers, body: None, .. }]
+                if modifiers == &[Modifier::Private]
+        ));
+        for source in ["val y = m get (k)", "val y = m set(k)"] {
+            let property = parse_property(source);
+            assert!(matches!(
+                property.init,
+                Some(Expression::BinaryOp(BinaryOperation {
+                    op: BinaryOperator::Infix(_),
+                    ..
+                }))
+            ));
+            assert!(property.accessors.is_empty());
+        }
+        let function = parse_function(
+            "fun f() {
+                m get (k)
+                m set(k)
+            }",
+        );
+        let statements = function.body.unwrap().statements;
+        assert_eq!(statements.len(), 2);
+        assert!(statements.iter().all(|stmt| matches!(
+            stmt,
+            Statement::Expression(Expression::BinaryOp(BinaryOperation {
+                op: BinaryOperator::Infix(_),
+                ..
+            }))
+        )));
+        assert!(matches!(
+            parse_property("val x = map get key").init,
+            Some(Expression::BinaryOp(BinaryOperation {
+                op: BinaryOperator::Infix(_),
+                ..
+            }))
+        ));
+
+        let property = parse_property(
+            r#"var z: String = ""
+                @Inject get
+                internal set"#,
+        );
+        assert!(matches!(
+            property.accessors.as_slice(),
+            [
+                PropertyAccessor::Getter { annotations, body: None, .. },
+                PropertyAccessor::Setter { field: None, body: None, .. },
+            ] if annotations.len() == 1
+        ));
+
+        assert!(parse("val x get() = 1 get() = 2").is_err());
+    }
+
+    #[test]
+    fn local_properties_have_no_accessors() {
+        let stmt = stmt_parser();
+        let (expr, nested, init) = expr_parsers(stmt.clone());
+        let parser = local_declaration_parser(stmt, expr, nested, init)
+            .then(stmt_parser())
+            .then_ignore(end());
+
+        assert!(matches!(
+            parser.parse("val set = 1\nset(2)"),
+            Ok((
+                Declaration {
+                    kind: DeclarationKind::Property(PropertyDeclaration {
+                        accessors,
+                        ..
+                    }),
+                    ..
+                },
+                Statement::Expression(Expression::Call(_)),
+            )) if accessors.is_empty()
+        ));
+    }
+
+    #[test]
+    fn parse_type_alias() {
+        assert_eq!(
+            parse("internal typealias Table<K> = Map<K, List<K>>")
+                .unwrap()
+                .kind,
+            DeclarationKind::TypeAlias(TypeAliasDeclaration {
+                modifiers: vec![Modifier::Internal],
+                name: "Table".to_string(),
+                type_params: vec![TypeParam {
+                    annotations: vec![],
+                    modifiers: vec![],
+                    name: "K".to_string(),
+                    bounds: vec![],
+                }],
+                ty: simple_type(
+                    "Map",
+                    vec![
+                        simple_type("K", vec![]),
+                        simple_type("List", vec![simple_type("K", vec![])]),
+                    ]
+                ),
+            })
+        );
+
+        assert!(matches!(
+            parse("typealias Handler<T> = suspend (T) -> Unit").unwrap().kind,
+            DeclarationKind::TypeAlias(TypeAliasDeclaration {
+                ty: Type::Function(function),
+                ..
+            }) if function.is_suspend
+        ));
+    }
 }
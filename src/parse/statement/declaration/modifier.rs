@@ -0,0 +1,113 @@
+use crate::{
+    ast::{AnnotationSet, Expression, Modifier},
+    parse::{
+        annotation::annotation_parser,
+        ident::keyword,
+        ty::type_parser,
+        whitespace::{whitespace, Pad},
+    },
+};
+use chumsky::prelude::*;
+
+/// Parses a single modifier keyword, or a `context(A, B)` receiver list.
+///
+/// Modifiers are soft keywords, so one is only recognized when another
+/// modifier, an annotation or the declaration itself follows; otherwise
+/// `data` in `fun f(data: Int)` would be taken for a modifier.
+pub fn modifier_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Modifier, Error = Simple<char>> + Clone + 'a {
+    let simple = choice((
+        choice((
+            keyword("public").to(Modifier::Public),
+            keyword("internal").to(Modifier::Internal),
+            keyword("protected").to(Modifier::Protected),
+            keyword("private").to(Modifier::Private),
+        )),
+        choice((
+            keyword("open").to(Modifier::Open),
+            keyword("final").to(Modifier::Final),
+            keyword("abstract").to(Modifier::Abstract),
+            keyword("sealed").to(Modifier::Sealed),
+        )),
+        choice((
+            keyword("data").to(Modifier::Data),
+            keyword("value").to(Modifier::Value),
+            keyword("enum").to(Modifier::Enum),
+            keyword("annotation").to(Modifier::Annotation),
+            keyword("inner").to(Modifier::Inner),
+            keyword("companion").to(Modifier::Companion),
+        )),
+        choice((
+            keyword("override").to(Modifier::Override),
+            keyword("lateinit").to(Modifier::Lateinit),
+            keyword("const").to(Modifier::Const),
+        )),
+        choice((
+            keyword("tailrec").to(Modifier::Tailrec),
+            keyword("operator").to(Modifier::Operator),
+            keyword("infix").to(Modifier::Infix),
+            keyword("inline").to(Modifier::Inline),
+            keyword("external").to(Modifier::External),
+            keyword("suspend").to(Modifier::Suspend),
+        )),
+        choice((
+            keyword("vararg").to(Modifier::Vararg),
+            keyword("noinline").to(Modifier::NoInline),
+            keyword("crossinline").to(Modifier::CrossInline),
+        )),
+        choice((
+            keyword("expect").to(Modifier::Expect),
+            keyword("actual").to(Modifier::Actual),
+        )),
+    ))
+    .then_ignore(
+        whitespace()
+            .then(filter(|c: &char| {
+                c.is_alphabetic() || matches!(c, '_' | '`' | '@')
+            }))
+            .rewind(),
+    );
+
+    // `fun` is only a modifier in `fun interface`.
+    let fun = keyword("fun")
+        .then_ignore(whitespace().then(keyword("interface")).rewind())
+        .to(Modifier::Fun);
+
+    let context = keyword("context")
+        .ignore_then(
+            type_parser(expr)
+                .separated_by(just(',').pad())
+                .allow_trailing()
+                .at_least(1)
+                .delimited_by(just('(').pad(), just(')')),
+        )
+        .map(Modifier::Context);
+
+    choice((simple, fun, context)).pad().boxed()
+}
+
+/// Parses the annotations and modifiers preceding a declaration, which may
+/// appear in any order.
+pub fn modifiers_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, (Vec<AnnotationSet>, Vec<Modifier>), Error = Simple<char>>
+       + Clone
+       + 'a {
+    annotation_parser(expr.clone())
+        .map(Ok)
+        .or(modifier_parser(expr).map(Err))
+        .repeated()
+        .map(|items| {
+            let mut annotations = Vec::new();
+            let mut modifiers = Vec::new();
+            for item in items {
+                match item {
+                    Ok(annotation) => annotations.push(annotation),
+                    Err(modifier) => modifiers.push(modifier),
+                }
+            }
+            (annotations, modifiers)
+        })
+        .boxed()
+}
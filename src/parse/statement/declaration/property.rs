@@ -1,50 +1,257 @@
-use crate::ast::*;
-use crate::parse::expression::expr_parser;
-use crate::parse::statement::inheritance_parser;
-use crate::parse::statement::visibility_parser;
-use crate::parse::ty::type_parser;
+use crate::{
+    ast::*,
+    parse::{
+        annotation::annotation_parser,
+        ident::{ident_parser, keyword},
+        ty::{type_constraints_parser, type_params_parser, type_parser},
+        whitespace::{inline_whitespace, Pad},
+    },
+};
 use chumsky::prelude::*;
-use chumsky::text;
 
-pub fn property_parser() -> impl Parser<char, Stmt, Error = Simple<char>> {
-    let mutable = just("const")
-        .padded()
-        .or_not()
-        .then(just("val"))
+use super::{
+    function::{function_body_parser, receiver_name_parser},
+    modifier::modifiers_parser,
+};
+
+/// Parses a property declaration. Accessors are only allowed on member and
+/// top-level properties, so `with_accessors` is disabled for local ones.
+/// `init` parses the initializer, which has to stop before an accessor on
+/// the same line.
+pub fn property_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    init: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    with_accessors: bool,
+) -> impl Parser<
+    char,
+    (Vec<AnnotationSet>, PropertyDeclaration),
+    Error = Simple<char>,
+> + Clone
+       + 'a {
+    let mutable = keyword("val").to(false).or(keyword("var").to(true)).pad();
+    // Extension properties can't be destructured, so a receiver is only
+    // looked for when the name isn't parenthesized.
+    let extension = receiver_name_parser(nested.clone())
+        .then(
+            just(':')
+                .pad()
+                .ignore_then(type_parser(nested.clone()))
+                .or_not(),
+        )
+        .map(|((receiver, name), ty)| {
+            let var = VarDefinition {
+                annotations: Vec::new(),
+                name,
+                ty,
+            };
+            (
+                receiver,
+                Tuple {
+                    is_destructured: false,
+                    vars: vec![var],
+                },
+            )
+        });
+    let vars = just('(')
+        .rewind()
+        .ignore_then(tuple_parser(nested.clone()))
+        .map(|vars| (None, vars))
+        .or(extension)
+        .or(tuple_parser(nested.clone()).map(|vars| (None, vars)));
+    let init = just('=')
         .to(false)
-        .or(just("var").to(true))
-        .padded();
-    let name = text::ident().padded();
-    let ty = just(':')
-        .padded()
-        .ignore_then(type_parser())
-        .or_not()
-        .padded();
-    let init = just("=")
-        .or(just("by"))
-        .padded()
-        .ignore_then(expr_parser())
-        .map(Box::new)
+        .or(keyword("by").to(true))
+        .pad()
+        .then(init)
+        .or_not();
+    let accessors = just(';')
+        .pad()
         .or_not()
-        .padded();
+        .ignore_then(accessor_parser(stmt, expr, nested.clone()))
+        .repeated()
+        .at_most(if with_accessors { 2 } else { 0 });
 
-    visibility_parser()
-        .or_not()
-        .then(inheritance_parser().or_not())
+    modifiers_parser(nested.clone())
         .then(mutable)
-        .then(name)
-        .then(ty)
+        .then(type_params_parser(nested.clone()).or_not())
+        .then(vars)
+        .then(type_constraints_parser(nested.clone()).or_not())
         .then(init)
-        .map(
-            |(((((visibility, inheritance), mutable), name), ty), init)| {
-                Stmt::Declaration(DeclarationKind::Property {
-                    visibility,
-                    inheritance,
-                    mutable,
-                    name,
-                    ty,
+        .then(accessors)
+        .try_map(
+            |(
+                (
+                    (
+                        (
+                            (
+                                ((annotations, modifiers), is_mutable),
+                                type_params,
+                            ),
+                            (receiver, vars),
+                        ),
+                        bounds,
+                    ),
                     init,
-                })
+                ),
+                accessors,
+            ),
+             span| {
+                if let [first, second] = accessors.as_slice() {
+                    if std::mem::discriminant(first)
+                        == std::mem::discriminant(second)
+                    {
+                        return Err(Simple::custom(
+                        span,
+                        "a property can only have one getter and one setter",
+                    ));
+                    }
+                }
+
+                let (is_delegated, init) = match init {
+                    Some((is_delegated, init)) => (is_delegated, Some(init)),
+                    None => (false, None),
+                };
+
+                Ok((
+                    annotations,
+                    PropertyDeclaration {
+                        modifiers,
+                        is_mutable,
+                        is_delegated,
+                        type_params: type_params.unwrap_or_default(),
+                        vars,
+                        receiver,
+                        bounds: bounds.unwrap_or_default(),
+                        init,
+                        accessors,
+                    },
+                ))
+            },
+        )
+}
+
+pub fn accessor_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+    nested: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, PropertyAccessor, Error = Simple<char>> + Clone + 'a {
+    let return_ty = just(':')
+        .pad()
+        .ignore_then(type_parser(nested.clone()))
+        .or_not();
+    let body = function_body_parser(stmt, expr);
+
+    let getter = keyword("get")
+        .pad()
+        .ignore_then(
+            just('(')
+                .pad()
+                .then(just(')').pad())
+                .ignore_then(return_ty.clone())
+                .then(body.clone())
+                .or_not(),
+        )
+        .map(|signature| match signature {
+            Some((return_ty, body)) => (return_ty, Some(body)),
+            None => (None, None),
+        });
+
+    let field = var_parser(nested.clone())
+        .then_ignore(just(',').pad().or_not())
+        .delimited_by(just('('), just(')').pad())
+        .pad()
+        .map(|VarDefinition { name, ty, .. }| PropertySetterField { name, ty });
+    let setter = keyword("set")
+        .pad()
+        .ignore_then(field.then(return_ty).then(body).or_not())
+        .map(|signature| match signature {
+            Some(((field, return_ty), body)) => {
+                (Some(field), return_ty, Some(body))
+            }
+            None => (None, None, None),
+        });
+
+    modifiers_parser(nested)
+        .then(getter.map(Ok).or(setter.map(Err)))
+        .map(|((annotations, modifiers), accessor)| match accessor {
+            Ok((return_ty, body)) => PropertyAccessor::Getter {
+                annotations,
+                modifiers,
+                return_ty,
+                body,
+            },
+            Err((field, return_ty, body)) => PropertyAccessor::Setter {
+                annotations,
+                modifiers,
+                field,
+                return_ty,
+                body,
             },
+        })
+}
+
+/// Matches the start of a property accessor, such as `private set` or
+/// `get() = ...`, without parsing its body. Used to end a property
+/// initializer on the same line.
+pub fn accessor_start_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, (), Error = Simple<char>> + Clone + 'a {
+    let line_end =
+        inline_whitespace().then(one_of(";}\r\n").ignored().or(end()));
+    let getter = keyword("get")
+        .then(just('(').pad())
+        .then(just(')'))
+        .ignored();
+    let setter = keyword("set")
+        .then(
+            none_of(")")
+                .repeated()
+                .delimited_by(just('(').pad(), just(')')),
         )
+        .then(inline_whitespace())
+        .then(one_of("{=:"))
+        .ignored();
+    let bare = keyword("get").or(keyword("set")).then(line_end).ignored();
+
+    modifiers_parser(expr)
+        .ignore_then(choice((getter, setter, bare)))
+        .boxed()
+}
+
+pub fn var_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, VarDefinition, Error = Simple<char>> + Clone + 'a {
+    annotation_parser(expr.clone())
+        .repeated()
+        .then(ident_parser().pad())
+        .then(just(':').pad().ignore_then(type_parser(expr)).or_not())
+        .map(|((annotations, name), ty)| VarDefinition {
+            annotations,
+            name,
+            ty,
+        })
+}
+
+/// Parses either a single variable or a parenthesized destructuring
+/// declaration such as `(a, b: Int)`.
+pub fn tuple_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Tuple, Error = Simple<char>> + Clone + 'a {
+    choice((
+        var_parser(expr.clone())
+            .separated_by(just(',').pad())
+            .allow_trailing()
+            .delimited_by(just('('), just(')').pad())
+            .map(|vars| Tuple {
+                is_destructured: true,
+                vars,
+            }),
+        var_parser(expr).map(|var| Tuple {
+            is_destructured: false,
+            vars: vec![var],
+        }),
+    ))
+    .pad()
 }
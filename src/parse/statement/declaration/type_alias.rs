@@ -0,0 +1,38 @@
+use crate::{
+    ast::*,
+    parse::{
+        ident::{ident_parser, keyword},
+        ty::{type_params_parser, type_parser},
+        whitespace::Pad,
+    },
+};
+use chumsky::prelude::*;
+
+use super::modifier::modifiers_parser;
+
+pub fn type_alias_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<
+    char,
+    (Vec<AnnotationSet>, TypeAliasDeclaration),
+    Error = Simple<char>,
+> + Clone
+       + 'a {
+    modifiers_parser(expr.clone())
+        .then_ignore(keyword("typealias").pad())
+        .then(ident_parser().pad())
+        .then(type_params_parser(expr.clone()).or_not())
+        .then_ignore(just('=').pad())
+        .then(type_parser(expr))
+        .map(|((((annotations, modifiers), name), type_params), ty)| {
+            (
+                annotations,
+                TypeAliasDeclaration {
+                    modifiers,
+                    name,
+                    type_params: type_params.unwrap_or_default(),
+                    ty,
+                },
+            )
+        })
+}
@@ -1,87 +1,54 @@
 pub mod declaration;
 
-use crate::ast::*;
+use crate::{
+    ast::*,
+    parse::whitespace::{inline_whitespace, Pad},
+};
 use chumsky::prelude::*;
-use declaration::declaration_parser;
+use declaration::local_declaration_parser;
 
-use super::expression::expr_parser;
+use super::expression::expr_parsers;
 
-pub fn stmt_parser() -> impl Parser<char, Stmt, Error = Simple<char>> + Clone {
+/// Parses a statement, which has to be followed by a semicolon, a line break
+/// or the end of the enclosing block.
+pub fn stmt_parser(
+) -> impl Parser<char, Statement, Error = Simple<char>> + Clone {
     recursive(|stmt| {
+        let (expr, nested, init) = expr_parsers(stmt.clone());
+        let terminator = just(';')
+            .pad()
+            .repeated()
+            .at_least(1)
+            .ignored()
+            .or(one_of("\r\n}").rewind().ignored())
+            .or(end());
+
+        // Declarations skip the whitespace before them themselves, so that
+        // they can pick up their doc comment.
         choice((
-            declaration_parser(stmt.clone()),
-            expr_stmt_parser(),
-            return_parser(),
-            scope_parser(stmt),
+            local_declaration_parser(stmt, expr.clone(), nested, init)
+                .map(Statement::Declaration)
+                .then_ignore(inline_whitespace()),
+            expr.map(Statement::Expression).pad(),
         ))
-        .padded()
-        .then_ignore(just(';').or(just('\n')).or(just('\r')).repeated())
-        .padded()
+        .then_ignore(terminator)
+        .boxed()
     })
 }
 
-fn expr_stmt_parser() -> impl Parser<char, Stmt, Error = Simple<char>> {
-    let expr = expr_parser();
-    expr.map(|expr| Stmt::ExprStmt(Box::new(expr)))
+pub fn block_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Block, Error = Simple<char>> + Clone + 'a {
+    stmt.repeated()
+        .delimited_by(just('{').pad(), just('}').pad())
+        .map(|statements| Block { statements })
 }
 
-pub fn return_parser() -> impl Parser<char, Stmt, Error = Simple<char>> {
-    just("return")
-        .padded()
-        .ignore_then(expr_parser().or_not())
-        .map(|expr| Stmt::Return(expr.map(Box::new)))
-}
-
-pub fn block_parser(
-    stmt_parser: impl Parser<char, Stmt, Error = Simple<char>> + Clone,
-) -> impl Parser<char, Block, Error = Simple<char>> {
-    choice((
-        just('{')
-            .padded()
-            .ignore_then(stmt_parser.clone().map(Box::new).repeated())
-            .then_ignore(just('}').padded())
-            .or_not()
-            .map(|stmts| Block {
-                statements: stmts.unwrap_or_default(),
-            }),
-        stmt_parser.or_not().map(|stmt| Block {
-            statements: stmt.map_or_else(Vec::new, |stmt| vec![Box::new(stmt)]),
-        }),
-    ))
-}
-
-pub fn scope_parser(
-    stmt_parser: impl Parser<char, Stmt, Error = Simple<char>> + Clone,
-) -> impl Parser<char, Stmt, Error = Simple<char>> {
-    just('{')
-        .padded()
-        .ignore_then(stmt_parser.clone().map(Box::new).repeated())
-        .then_ignore(just('}').padded())
-        .or_not()
-        .map(|stmts| {
-            Stmt::Scope(Block {
-                statements: stmts.unwrap_or_default(),
-            })
-        })
-}
-
-pub fn visibility_parser(
-) -> impl Parser<char, VisibilityModifier, Error = Simple<char>> {
-    choice((
-        just("public").to(VisibilityModifier::Public),
-        just("internal").to(VisibilityModifier::Internal),
-        just("protected").to(VisibilityModifier::Protected),
-        just("private").to(VisibilityModifier::Private),
-    ))
-    .padded()
-}
-
-pub fn inheritance_parser(
-) -> impl Parser<char, InheritanceModifier, Error = Simple<char>> {
-    choice((
-        just("open").to(InheritanceModifier::Open),
-        just("final").to(InheritanceModifier::Final),
-        just("abstract").to(InheritanceModifier::Abstract),
-    ))
-    .padded()
+/// Parses the body of `if`, `for`, `while` and `do`, which is either a
+/// `{ ... }` block or a single expression.
+pub fn control_body_parser<'a>(
+    stmt: impl Parser<char, Statement, Error = Simple<char>> + Clone + 'a,
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a {
+    block_parser(stmt).map(Expression::Block).or(expr)
 }
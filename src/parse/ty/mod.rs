@@ -1,119 +1,476 @@
-use crate::ast::*;
+use crate::{
+    ast::*,
+    parse::{
+        annotation::annotation_parser,
+        ident::{ident_parser, keyword},
+        not_followed_by,
+        whitespace::{inline_whitespace, Pad},
+    },
+};
 use chumsky::prelude::*;
-use text::ident;
 
-pub fn type_parser() -> impl Parser<char, Type, Error = Simple<char>> {
+pub fn type_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Type, Error = Simple<char>> + Clone + 'a {
+    let annotations = annotation_parser(expr).repeated();
+
     recursive(|type_parser| {
-        let type_kind = choice((
-            just("Byte").to(TypeKind::Byte),
-            just("UByte").to(TypeKind::UByte),
-            just("Short").to(TypeKind::Short),
-            just("UShort").to(TypeKind::UShort),
-            just("Int").to(TypeKind::Int),
-            just("UInt").to(TypeKind::UInt),
-            just("Long").to(TypeKind::Long),
-            just("ULong").to(TypeKind::ULong),
-            just("Float").to(TypeKind::Float),
-            just("Double").to(TypeKind::Double),
-            just("Char").to(TypeKind::Char),
-            just("String").to(TypeKind::String),
-            just("Array").to(TypeKind::Array),
-            just("ByteArray").to(TypeKind::ByteArray),
-            just("UByteArray").to(TypeKind::UByteArray),
-            just("ShortArray").to(TypeKind::ShortArray),
-            just("UShortArray").to(TypeKind::UShortArray),
-            just("IntArray").to(TypeKind::IntArray),
-            just("UIntArray").to(TypeKind::UIntArray),
-            just("LongArray").to(TypeKind::LongArray),
-            just("ULongArray").to(TypeKind::ULongArray),
-            just("FloatArray").to(TypeKind::FloatArray),
-            just("DoubleArray").to(TypeKind::DoubleArray),
-            just("BooleanArray").to(TypeKind::BooleanArray),
-            just("CharArray").to(TypeKind::CharArray),
+        let variance = choice((
+            keyword("out").to(BoundKind::Covariant),
+            keyword("in").to(BoundKind::Contravariant),
         ))
-        .or(choice((
-            just("Map").to(TypeKind::Map),
-            just("MutableMap").to(TypeKind::MutableMap),
-            just("EmptyMap").to(TypeKind::EmptyMap),
-            just("Set").to(TypeKind::Set),
-            just("MutableSet").to(TypeKind::MutableSet),
-            just("EmptySet").to(TypeKind::EmptySet),
-            just("List").to(TypeKind::List),
-            just("MutableList").to(TypeKind::MutableList),
-            just("EmptyList").to(TypeKind::EmptyList),
-            just("Boolean").to(TypeKind::Boolean),
-            just("Any").to(TypeKind::Any),
-            just("Nothing").to(TypeKind::Nothing),
-            just("Unit").to(TypeKind::Unit),
-        )))
-        .or(ident().map(TypeKind::Object))
-        .padded();
-
-        let attributes = type_parser
+        .pad()
+        .or_not()
+        .map(|variance| variance.unwrap_or(BoundKind::Unconstrained));
+        let star = just('*').pad().to(Type::Simple(Box::new(SimpleType {
+            qualifier: None,
+            name: None,
+            type_args: Vec::new(),
+            is_nullable: false,
+        })));
+        let type_arg = annotations
             .clone()
-            .separated_by(just(',').padded())
-            .delimited_by(just('<'), just('>'))
-            .padded()
+            .then(
+                star.map(|ty| (BoundKind::Unconstrained, ty))
+                    .or(variance.then(type_parser.clone())),
+            )
+            .map(|(annotations, (kind, ty))| TypeBound {
+                annotations,
+                ty,
+                kind,
+            });
+        let type_args = type_arg
+            .separated_by(just(',').pad())
+            .allow_trailing()
+            .delimited_by(just('<'), just('>').pad())
+            .pad();
+
+        let user_type = ident_parser()
+            .pad()
+            .then(type_args.or_not())
+            .separated_by(just('.'))
+            .at_least(1)
+            .map(|parts| {
+                let ty = parts.into_iter().fold(
+                    None,
+                    |qualifier, (name, type_args)| {
+                        Some(SimpleType {
+                            qualifier: qualifier.map(Box::new),
+                            name: Some(name),
+                            type_args: type_args.unwrap_or_default(),
+                            is_nullable: false,
+                        })
+                    },
+                );
+                Type::Simple(Box::new(ty.unwrap()))
+            });
+
+        let param = ident_parser()
+            .pad()
+            .then_ignore(just(':').pad())
             .or_not()
-            .map(|attrs| attrs.unwrap_or_default());
+            .then(type_parser.clone())
+            .map(|(name, ty)| AnonymousParam { name, ty });
+        let params = param
+            .separated_by(just(',').pad())
+            .allow_trailing()
+            .delimited_by(just('('), just(')').pad())
+            .pad();
+        let return_ty = just("->").pad().ignore_then(type_parser);
 
-        let nullable = just('?').or_not().map(|n| n.is_some());
+        // `(A, B) -> C` is a function type, while `(A)` on its own is
+        // merely a parenthesized type.
+        let parenthesized = params
+            .clone()
+            .then(return_ty.clone().or_not())
+            .try_map(|(mut params, return_ty), span| match return_ty {
+                Some(return_ty) => Ok(function_type(None, params, return_ty)),
+                None if params.len() == 1 && params[0].name.is_none() => {
+                    Ok(params.remove(0).ty)
+                }
+                None => Err(Simple::custom(span, "expected `->`")),
+            });
 
-        type_kind.then(attributes).then(nullable).map(
-            |((kind, attributes), nullable)| Type {
-                kind,
-                nullable,
-                attributes: attributes.into_iter().map(Box::new).collect(),
-            },
-        )
+        let nullable_type = user_type
+            .or(parenthesized)
+            .then(
+                // A `?` followed by a single `:` is an elvis operator.
+                just('?')
+                    .then_ignore(
+                        just("::").rewind().ignored().or(not_followed_by(":")),
+                    )
+                    .then_ignore(inline_whitespace())
+                    .repeated(),
+            )
+            .map(|(ty, nullable)| {
+                if nullable.is_empty() {
+                    ty
+                } else {
+                    make_nullable(ty)
+                }
+            })
+            .boxed();
+
+        let receiver_function = just('.')
+            .ignore_then(params)
+            .then(return_ty)
+            .map(|(params, return_ty)| Ok((params, return_ty)));
+        let definitely_non_null = just('&')
+            .then_ignore(not_followed_by("&"))
+            .pad()
+            .ignore_then(nullable_type.clone())
+            .map(Err);
+
+        annotations
+            .then(keyword("suspend").pad().or_not())
+            .then(nullable_type)
+            .then(receiver_function.or(definitely_non_null).or_not())
+            .try_map(|(((annotations, suspend), ty), suffix), span| {
+                let ty = match suffix {
+                    Some(Ok((params, return_ty))) => {
+                        function_type(Some(ty), params, return_ty)
+                    }
+                    Some(Err(rhs)) => Type::DefinitelyNonNull(Box::new(
+                        DefinitelyNonNullType { lhs: ty, rhs },
+                    )),
+                    None => ty,
+                };
+
+                let ty = match (suspend, ty) {
+                    (Some(()), Type::Function(mut function)) => {
+                        function.is_suspend = true;
+                        Type::Function(function)
+                    }
+                    (Some(()), _) => {
+                        return Err(Simple::custom(
+                            span,
+                            "`suspend` is only allowed on function types",
+                        ))
+                    }
+                    (None, ty) => ty,
+                };
+
+                Ok(if annotations.is_empty() {
+                    ty
+                } else {
+                    Type::Annotated(Box::new(AnnotatedType { annotations, ty }))
+                })
+            })
+            .pad()
     })
 }
 
+fn function_type(
+    receiver: Option<Type>,
+    params: Vec<AnonymousParam>,
+    return_ty: Type,
+) -> Type {
+    Type::Function(Box::new(FunctionType {
+        receiver,
+        params,
+        return_ty,
+        is_nullable: false,
+        is_suspend: false,
+    }))
+}
+
+fn make_nullable(ty: Type) -> Type {
+    match ty {
+        Type::Simple(mut simple) => {
+            simple.is_nullable = true;
+            Type::Simple(simple)
+        }
+        Type::Function(mut function) => {
+            function.is_nullable = true;
+            Type::Function(function)
+        }
+        ty => ty,
+    }
+}
+
+pub fn type_params_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Vec<TypeParam>, Error = Simple<char>> + Clone + 'a {
+    let modifier = choice((
+        keyword("in").to(Modifier::In),
+        keyword("out").to(Modifier::Out),
+        keyword("reified").to(Modifier::Reified),
+    ))
+    .pad();
+    let bound =
+        just(':')
+            .pad()
+            .ignore_then(type_parser(expr.clone()))
+            .map(|ty| TypeBound {
+                annotations: Vec::new(),
+                ty,
+                kind: BoundKind::Unconstrained,
+            });
+
+    annotation_parser(expr)
+        .map(Ok)
+        .or(modifier.map(Err))
+        .repeated()
+        .then(ident_parser().pad())
+        .then(bound.or_not())
+        .map(|((prefix, name), bound)| {
+            let mut annotations = Vec::new();
+            let mut modifiers = Vec::new();
+            for item in prefix {
+                match item {
+                    Ok(annotation) => annotations.push(annotation),
+                    Err(modifier) => modifiers.push(modifier),
+                }
+            }
+            TypeParam {
+                annotations,
+                modifiers,
+                name,
+                bounds: bound.into_iter().collect(),
+            }
+        })
+        .separated_by(just(',').pad())
+        .allow_trailing()
+        .delimited_by(just('<'), just('>').pad())
+        .pad()
+}
+
+/// Parses a `where T : A, U : B` clause.
+pub fn type_constraints_parser<'a>(
+    expr: impl Parser<char, Expression, Error = Simple<char>> + Clone + 'a,
+) -> impl Parser<char, Vec<TypeConstraint>, Error = Simple<char>> + Clone + 'a {
+    let constraint = annotation_parser(expr.clone())
+        .repeated()
+        .then(ident_parser().pad())
+        .then_ignore(just(':').pad())
+        .then(type_parser(expr))
+        .map(|((annotations, name), ty)| TypeConstraint {
+            annotations,
+            name,
+            ty,
+        });
+
+    keyword("where")
+        .pad()
+        .ignore_then(constraint.separated_by(just(',').pad()).at_least(1))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse::{expression::expr_parsers, statement::stmt_parser};
+    use chumsky::error::SimpleReason;
+
+    fn parse(source: &str) -> Result<Type, Vec<Simple<char>>> {
+        type_parser(expr_parsers(stmt_parser()).0)
+            .then_ignore(end())
+            .parse(source)
+    }
+
+    fn simple(name: &str, type_args: Vec<Type>, is_nullable: bool) -> Type {
+        Type::Simple(Box::new(SimpleType {
+            qualifier: None,
+            name: Some(name.to_string()),
+            type_args: type_args
+                .into_iter()
+                .map(|ty| TypeBound {
+                    annotations: Vec::new(),
+                    ty,
+                    kind: BoundKind::Unconstrained,
+                })
+                .collect(),
+            is_nullable,
+        }))
+    }
+
+    fn param(ty: Type) -> AnonymousParam {
+        AnonymousParam { name: None, ty }
+    }
 
     #[test]
     fn test_type_parser() {
         assert_eq!(
-            type_parser()
-                .parse("Triple<UInt, Array<String>, HashMap<Key, Value>>?"),
-            Ok(Type {
-                kind: TypeKind::Object("Triple".to_string(),),
-                nullable: true,
-                attributes: vec![
-                    Box::new(Type {
-                        kind: TypeKind::UInt,
-                        nullable: false,
-                        attributes: vec![],
-                    }),
-                    Box::new(Type {
-                        kind: TypeKind::Array,
-                        nullable: false,
-                        attributes: vec![Box::new(Type {
-                            kind: TypeKind::String,
-                            nullable: false,
-                            attributes: vec![],
-                        })],
-                    }),
-                    Box::new(Type {
-                        kind: TypeKind::Object("HashMap".to_string()),
-                        nullable: false,
-                        attributes: vec![
-                            Box::new(Type {
-                                kind: TypeKind::Object("Key".to_string()),
-                                nullable: false,
-                                attributes: vec![],
-                            }),
-                            Box::new(Type {
-                                kind: TypeKind::Object("Value".to_string()),
-                                nullable: false,
-                                attributes: vec![],
-                            }),
+            parse("Triple<UInt, Array<String>, HashMap<Key, Value>>?"),
+            Ok(simple(
+                "Triple",
+                vec![
+                    simple("UInt", vec![], false),
+                    simple(
+                        "Array",
+                        vec![simple("String", vec![], false)],
+                        false
+                    ),
+                    simple(
+                        "HashMap",
+                        vec![
+                            simple("Key", vec![], false),
+                            simple("Value", vec![], false),
                         ],
-                    }),
+                        false
+                    ),
                 ],
-            }),
+                true
+            )),
         );
     }
+
+    #[test]
+    fn parse_qualified_type() {
+        let Ok(Type::Simple(entry)) = parse("java.util.Map.Entry<K, V>") else {
+            panic!("expected a simple type");
+        };
+
+        assert_eq!(entry.name.as_deref(), Some("Entry"));
+        assert_eq!(entry.type_args.len(), 2);
+
+        let mut qualifiers = Vec::new();
+        let mut qualifier = entry.qualifier;
+        while let Some(ty) = qualifier {
+            qualifiers.push(ty.name.unwrap());
+            assert!(ty.type_args.is_empty());
+            qualifier = ty.qualifier;
+        }
+        assert_eq!(qualifiers, vec!["Map", "util", "java"]);
+
+        assert!(matches!(
+            parse("Outer<A>.Inner<B>"),
+            Ok(Type::Simple(inner)) if matches!(
+                inner.qualifier.as_deref(),
+                Some(SimpleType { type_args, .. }) if type_args.len() == 1
+            )
+        ));
+    }
+
+    #[test]
+    fn parse_projections() {
+        let Ok(Type::Simple(ty)) = parse("Map<out K, in V>") else {
+            panic!("expected a simple type");
+        };
+        assert_eq!(
+            ty.type_args
+                .iter()
+                .map(|arg| arg.kind.clone())
+                .collect::<Vec<_>>(),
+            vec![BoundKind::Covariant, BoundKind::Contravariant]
+        );
+
+        assert_eq!(
+            parse("List<*>"),
+            Ok(Type::Simple(Box::new(SimpleType {
+                qualifier: None,
+                name: Some("List".to_string()),
+                type_args: vec![TypeBound {
+                    annotations: Vec::new(),
+                    ty: Type::Simple(Box::new(SimpleType {
+                        qualifier: None,
+                        name: None,
+                        type_args: vec![],
+                        is_nullable: false,
+                    })),
+                    kind: BoundKind::Unconstrained,
+                }],
+                is_nullable: false,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_function_types() {
+        assert_eq!(
+            parse("(Int, name: String) -> Unit"),
+            Ok(function_type(
+                None,
+                vec![
+                    param(simple("Int", vec![], false)),
+                    AnonymousParam {
+                        name: Some("name".to_string()),
+                        ty: simple("String", vec![], false),
+                    },
+                ],
+                simple("Unit", vec![], false)
+            ))
+        );
+
+        assert_eq!(
+            parse("Foo.(Bar) -> Baz"),
+            Ok(function_type(
+                Some(simple("Foo", vec![], false)),
+                vec![param(simple("Bar", vec![], false))],
+                simple("Baz", vec![], false)
+            ))
+        );
+
+        assert!(matches!(
+            parse("suspend () -> Unit"),
+            Ok(Type::Function(function))
+                if function.is_suspend && function.params.is_empty()
+        ));
+
+        assert!(matches!(
+            parse("(() -> Unit)?"),
+            Ok(Type::Function(function))
+                if function.is_nullable && !function.is_suspend
+        ));
+
+        assert_eq!(
+            parse("(Int) -> (String) -> Unit"),
+            Ok(function_type(
+                None,
+                vec![param(simple("Int", vec![], false))],
+                function_type(
+                    None,
+                    vec![param(simple("String", vec![], false))],
+                    simple("Unit", vec![], false)
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_parenthesized_types() {
+        assert_eq!(parse("(Int)"), Ok(simple("Int", vec![], false)));
+        assert_eq!(parse("(Int)?"), Ok(simple("Int", vec![], true)));
+        assert!(parse("(Int, Int)").is_err());
+    }
+
+    #[test]
+    fn parse_definitely_non_null_type() {
+        assert_eq!(
+            parse("T & Any"),
+            Ok(Type::DefinitelyNonNull(Box::new(DefinitelyNonNullType {
+                lhs: simple("T", vec![], false),
+                rhs: simple("Any", vec![], false),
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_annotated_types() {
+        let Ok(Type::Annotated(annotated)) = parse("@Ann T") else {
+            panic!("expected an annotated type");
+        };
+        assert_eq!(annotated.annotations.len(), 1);
+        assert_eq!(annotated.ty, simple("T", vec![], false));
+
+        assert!(matches!(
+            parse("@Ann suspend () -> Unit"),
+            Ok(Type::Annotated(annotated)) if matches!(
+                &annotated.ty,
+                Type::Function(function) if function.is_suspend
+            )
+        ));
+
+        let errors = parse("suspend T").unwrap_err();
+        assert!(errors.iter().any(|error| matches!(
+            error.reason(),
+            SimpleReason::Custom(message)
+                if message == "`suspend` is only allowed on function types"
+        )));
+    }
+
+    #[test]
+    fn parse_names_sharing_a_prefix() {
+        assert_eq!(parse("Integer"), Ok(simple("Integer", vec![], false)));
+        assert_eq!(parse("Intent"), Ok(simple("Intent", vec![], false)),);
+    }
 }
@@ -0,0 +1,73 @@
+use chumsky::prelude::*;
+
+/// Skips any amount of whitespace and comments, including line breaks.
+pub fn whitespace() -> impl Parser<char, (), Error = Simple<char>> + Clone {
+    trivia(true)
+}
+
+/// Skips whitespace and comments up to, but not including, the next line
+/// break.
+pub fn inline_whitespace() -> impl Parser<char, (), Error = Simple<char>> + Clone
+{
+    trivia(false)
+}
+
+/// Skips whitespace and comments, stopping at line breaks unless `multiline`
+/// is set. A line comment never includes the line break that ends it.
+pub fn trivia(
+    multiline: bool,
+) -> impl Parser<char, (), Error = Simple<char>> + Clone {
+    filter(move |c: &char| {
+        c.is_whitespace() && (multiline || !matches!(c, '\n' | '\r'))
+    })
+    .ignored()
+    .or(comment_parser())
+    .repeated()
+    .ignored()
+    .boxed()
+}
+
+/// Parses a `// line comment` or a `/* block comment */`. Block comments may
+/// be nested.
+pub fn comment_parser() -> impl Parser<char, (), Error = Simple<char>> + Clone {
+    let line = just("//")
+        .then(filter(|c: &char| !matches!(c, '\n' | '\r')).repeated())
+        .ignored();
+    let block = just("/*").ignore_then(block_comment_body()).ignored();
+
+    line.or(block)
+}
+
+/// Parses the rest of a block comment after its opening `/*`, returning its
+/// text up to the matching `*/`.
+pub fn block_comment_body(
+) -> impl Parser<char, String, Error = Simple<char>> + Clone {
+    recursive(|body| {
+        let nested = just("/*")
+            .ignore_then(body)
+            .map(|text: String| format!("/*{}*/", text));
+        let char = just("*/").not().map(String::from);
+
+        nested
+            .or(char)
+            .repeated()
+            .then_ignore(just("*/"))
+            .map(|parts| parts.concat())
+    })
+}
+
+pub trait Pad<O>: Parser<char, O, Error = Simple<char>> + Sized {
+    /// Skips whitespace around the parser. Line breaks after it are left in
+    /// place, since whether they end the current statement is up to the
+    /// parser that follows.
+    fn pad(self) -> impl Parser<char, O, Error = Simple<char>> + Clone
+    where
+        Self: Clone,
+    {
+        whitespace()
+            .ignore_then(self)
+            .then_ignore(inline_whitespace())
+    }
+}
+
+impl<O, P: Parser<char, O, Error = Simple<char>>> Pad<O> for P {}
@@ -1,8 +1,200 @@
-// use super::ast::*;
-// use chumsky::prelude::*;
+use crate::{ast::KotlinFile, parse::file::file_parser};
+use chumsky::prelude::*;
 
-// use crate::parse::statement::stmt_parser;
+pub type ParseError = Simple<char>;
 
-// pub fn parser() -> impl Parser<char, Vec<Stmt>, Error = Simple<char>> {
-//     stmt_parser().repeated().then_ignore(end())
-// }
+/// Parses the source code of a single `.kt` file.
+pub fn parse_file(source: &str) -> Result<KotlinFile, Vec<ParseError>> {
+    file_parser().parse(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::*;
+
+    #[test]
+    fn parse_simple_file() {
+        let file = parse_file(
+            r#"
+            package com.example
+
+            import kotlin.math.max
+
+            private const val LIMIT = 10
+
+            fun clamp(value: Int): Int {
+                if (value > LIMIT) return LIMIT
+                return value
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            file.package,
+            Some(Package {
+                modifiers: vec![],
+                names: vec!["com".to_string(), "example".to_string()],
+            })
+        );
+        assert_eq!(
+            file.imports,
+            vec![Import {
+                names: vec![
+                    "kotlin".to_string(),
+                    "math".to_string(),
+                    "max".to_string()
+                ],
+                is_wildcard: false,
+                alias: None,
+            }]
+        );
+        assert_eq!(file.declarations.len(), 2);
+        assert!(matches!(
+            &file.declarations[0].kind,
+            DeclarationKind::Property(PropertyDeclaration { modifiers, .. })
+                if modifiers.contains(&Modifier::Const)
+        ));
+        assert!(matches!(
+            &file.declarations[1].kind,
+            DeclarationKind::Function(FunctionDeclaration { name: Some(name), .. })
+                if name == "clamp"
+        ));
+    }
+
+    #[test]
+    fn line_breaks_end_binary_expressions() {
+        let file = parse_file(
+            r#"
+            fun main() {
+                total = a
+                println(total)
+                valid = a
+                    && b
+            }
+            "#,
+        )
+        .unwrap();
+
+        let DeclarationKind::Function(FunctionDeclaration {
+            body: Some(body),
+            ..
+        }) = &file.declarations[0].kind
+        else {
+            panic!("expected a function with a body");
+        };
+        assert_eq!(body.statements.len(), 3);
+    }
+
+    #[test]
+    fn infer_statement_separators() {
+        let statements = |source: &str| {
+            let file = parse_file(source).unwrap();
+            match &file.declarations[0].kind {
+                DeclarationKind::Function(FunctionDeclaration {
+                    body: Some(body),
+                    ..
+                }) => body.statements.clone(),
+                _ => panic!("expected a function with a body"),
+            }
+        };
+
+        assert_eq!(
+            statements(
+                "fun f() {
+                    val x = a
+                        .map { it }
+                        ?.filter { true }
+                }"
+            )
+            .len(),
+            1
+        );
+        assert_eq!(
+            statements(
+                "fun f() {
+                    check(a
+                        == b, c
+                        + d)
+                    val y = (1
+                        - 2)
+                }"
+            )
+            .len(),
+            2
+        );
+
+        assert!(matches!(
+            statements("fun f() {\n    return\n    foo()\n}").as_slice(),
+            [
+                Statement::Expression(Expression::Return(ReturnExpression {
+                    expr: None,
+                    ..
+                })),
+                Statement::Expression(Expression::Call(_)),
+            ]
+        ));
+        assert!(matches!(
+            statements("fun f() {\n    run\n    { a }\n}").as_slice(),
+            [
+                Statement::Expression(Expression::Reference(_)),
+                Statement::Expression(Expression::Lambda(_)),
+            ]
+        ));
+        assert_eq!(statements("fun f() { a; b;; c }").len(), 3);
+
+        assert!(parse_file("fun f() { a b }").is_err());
+    }
+
+    #[test]
+    fn skip_comments() {
+        let file = parse_file(
+            r#"
+            /* Licensed under /* nested */ the MIT license. */
+            package com.example // trailing
+
+            // A line comment.
+            import kotlin.math.max
+
+            fun main() { // opens the body
+                val total = a /* inline */ + b // sum
+                println(
+                    total, // first
+                    max(a, b) / 2,
+                )
+                /* before the end */
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(file.imports.len(), 1);
+        let DeclarationKind::Function(FunctionDeclaration {
+            body: Some(body),
+            ..
+        }) = &file.declarations[0].kind
+        else {
+            panic!("expected a function with a body");
+        };
+        assert_eq!(body.statements.len(), 2);
+
+        assert!(parse_file("/* unterminated /* */").is_err());
+    }
+
+    #[test]
+    fn parse_annotated_types() {
+        for source in [
+            "val x = y as @Ann T",
+            "fun f(x: @Ann T) {}",
+            "val f: @Ann () -> Unit = {}",
+        ] {
+            assert!(parse_file(source).is_ok(), "{source}");
+        }
+    }
+
+    #[test]
+    fn reject_invalid_file() {
+        assert!(parse_file("fun (").is_err());
+    }
+}